@@ -17,9 +17,12 @@ const INIT_TRANSITION_DONE: u64 = 1;
 /// 1. Appearance animations send a `TweenComplete` event with
 /// `INIT_TRANSITION_DONE` 2. The `enable_interaction_after_initial_animation`
 /// system adds a label component `InitTransitionDone` to any button component
-/// which completed its appearance animation, to mark it as active.
+/// which completed its appearance animation, to mark it as active. It also
+/// replaces the appearance animation with the hover one, idling at its start.
 /// 3. The `interaction` system only queries buttons with a `InitTransitionDone`
-/// marker.
+/// marker. It reverses the hover animation whenever the button is hovered or
+/// not anymore, so that the button scales up or back down smoothly from its
+/// current scale, even if the previous animation didn't finish.
 fn main() {
     App::default()
         .add_plugins(DefaultPlugins.set(WindowPlugin {
@@ -136,11 +139,28 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
 
 fn enable_interaction_after_initial_animation(
     mut commands: Commands,
+    mut query: Query<&mut Animator<Transform>>,
     mut reader: EventReader<TweenCompleted>,
 ) {
     for event in reader.iter() {
         if event.user_data == INIT_TRANSITION_DONE {
             commands.entity(event.entity).insert(InitTransitionDone);
+
+            // Scale-up animation played when hovering the button, initially
+            // completed backward so it idles at its start
+            let mut tween_hover = Tween::new(
+                EaseFunction::QuadraticIn,
+                Duration::from_millis(200),
+                TransformScaleLens {
+                    start: Vec3::ONE,
+                    end: Vec3::splat(1.1),
+                },
+            )
+            .with_direction(TweeningDirection::Backward);
+            tween_hover.set_progress(1.);
+            if let Ok(mut animator) = query.get_mut(event.entity) {
+                animator.set_tweenable(tween_hover);
+            }
         }
     }
 }
@@ -160,7 +180,6 @@ fn interaction(
     mut interaction_query: Query<
         (
             &mut Animator<Transform>,
-            &Transform,
             &Interaction,
             &mut BackgroundColor,
             &ButtonLabel,
@@ -168,7 +187,7 @@ fn interaction(
         (Changed<Interaction>, With<InitTransitionDone>),
     >,
 ) {
-    for (mut animator, transform, interaction, mut color, button_label) in &mut interaction_query {
+    for (mut animator, interaction, mut color, button_label) in &mut interaction_query {
         match *interaction {
             Interaction::Clicked => {
                 *color = CLICK_COLOR.into();
@@ -190,27 +209,15 @@ fn interaction(
             }
             Interaction::Hovered => {
                 *color = HOVER_COLOR.into();
-                animator.set_tweenable(Tween::new(
-                    EaseFunction::QuadraticIn,
-                    Duration::from_millis(200),
-                    TransformScaleLens {
-                        start: Vec3::ONE,
-                        end: Vec3::splat(1.1),
-                    },
-                ));
+                if animator.tweenable().direction().is_backward() {
+                    animator.reverse();
+                }
             }
             Interaction::None => {
                 *color = NORMAL_COLOR.into();
-                let start_scale = transform.scale;
-
-                animator.set_tweenable(Tween::new(
-                    EaseFunction::QuadraticIn,
-                    Duration::from_millis(200),
-                    TransformScaleLens {
-                        start: start_scale,
-                        end: Vec3::ONE,
-                    },
-                ));
+                if animator.tweenable().direction().is_forward() {
+                    animator.reverse();
+                }
             }
         }
     }
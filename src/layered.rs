@@ -0,0 +1,604 @@
+use bevy::prelude::*;
+
+use crate::{
+    tweenable::ValueTarget, AnimatorState, BoxedTweenable, Targetable, TimeSource, Tweenable,
+};
+
+/// Trait for components which can be animated by additive layers of a
+/// [`LayeredAnimator`].
+///
+/// An additive layer animates a scratch value starting from
+/// [`Default::default()`], and the difference between that animated value and
+/// the default one is then added on top of the actual component. For example, a
+/// [`Transform`] additive layer animating the scale from `1.` to `1.1` scales
+/// the component by 10% whatever its actual scale.
+pub trait AdditiveBlend: Default + Send + Sync + 'static {
+    /// Add to `self` an offset animated relative to the default value.
+    fn add_offset(&mut self, offset: &Self);
+
+    /// Remove from `self` an offset previously added with
+    /// [`add_offset()`](AdditiveBlend::add_offset).
+    fn remove_offset(&mut self, offset: &Self);
+}
+
+impl AdditiveBlend for Transform {
+    fn add_offset(&mut self, offset: &Self) {
+        self.translation += offset.translation;
+        self.rotation *= offset.rotation;
+        self.scale *= offset.scale;
+    }
+
+    fn remove_offset(&mut self, offset: &Self) {
+        self.translation -= offset.translation;
+        self.rotation *= offset.rotation.inverse();
+        // A null scale cannot be reverted; leave those axes unchanged
+        self.scale = Vec3::select(
+            offset.scale.cmpeq(Vec3::ZERO),
+            self.scale,
+            self.scale / offset.scale,
+        );
+    }
+}
+
+/// How a layer of a [`LayeredAnimator`] combines with the layers below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayerBlend {
+    /// The layer writes the fields it animates, overwriting the value of any
+    /// layer below. This is the default.
+    #[default]
+    Overwrite,
+    /// The layer adds its animated offset on top of the layers below. See
+    /// [`AdditiveBlend`].
+    Additive,
+}
+
+/// Additive blending functions of a layer, captured when the layer is created
+/// so that only additive layers require [`AdditiveBlend`].
+struct AdditiveFns<T> {
+    default: fn() -> T,
+    add: fn(&mut T, &T),
+    remove: fn(&mut T, &T),
+}
+
+impl<T: AdditiveBlend> AdditiveFns<T> {
+    fn new() -> Self {
+        Self {
+            default: T::default,
+            add: T::add_offset,
+            remove: T::remove_offset,
+        }
+    }
+}
+
+/// Offset added by an additive layer during the last update, to be removed on
+/// the next one.
+struct AppliedOffset<T> {
+    offset: T,
+    remove: fn(&mut T, &T),
+}
+
+/// A single named animation of a [`LayeredAnimator`].
+///
+/// Like an [`Animator`], a layer has its own playback state and speed.
+///
+/// [`Animator`]: crate::Animator
+pub struct AnimatorLayer<T> {
+    /// Control if this layer is played or not.
+    pub state: AnimatorState,
+    name: String,
+    tweenable: BoxedTweenable<T>,
+    speed: f32,
+    priority: i32,
+    additive: Option<AdditiveFns<T>>,
+    applied: Option<T>,
+}
+
+impl<T: 'static> AnimatorLayer<T> {
+    /// Create a new layer overwriting the fields it animates.
+    #[must_use]
+    pub fn new(name: impl Into<String>, tween: impl Tweenable<T> + 'static) -> Self {
+        Self {
+            state: default(),
+            name: name.into(),
+            tweenable: Box::new(tween),
+            speed: 1.,
+            priority: 0,
+            additive: None,
+            applied: None,
+        }
+    }
+
+    /// Create a new layer adding its animated offset on top of the layers
+    /// below it.
+    ///
+    /// See [`AdditiveBlend`] for details.
+    #[must_use]
+    pub fn additive(name: impl Into<String>, tween: impl Tweenable<T> + 'static) -> Self
+    where
+        T: AdditiveBlend,
+    {
+        let mut layer = Self::new(name, tween);
+        layer.additive = Some(AdditiveFns::new());
+        layer
+    }
+}
+
+impl<T> AnimatorLayer<T> {
+    /// Set the priority of the layer. Defaults to 0.
+    ///
+    /// Layers are applied by increasing priority, so that a layer overwrites
+    /// the fields animated by layers of lower priority. Layers with the same
+    /// priority are applied in the order they were added.
+    #[must_use]
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Set the initial playback state of the layer.
+    #[must_use]
+    pub fn with_state(mut self, state: AnimatorState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Set the initial speed of the layer. See [`Animator::set_speed()`] for
+    /// details.
+    ///
+    /// [`Animator::set_speed()`]: crate::Animator::set_speed
+    #[must_use]
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Get the name of the layer.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Set the priority of the layer.
+    ///
+    /// See [`with_priority()`](AnimatorLayer::with_priority) for details.
+    pub fn set_priority(&mut self, priority: i32) {
+        self.priority = priority;
+    }
+
+    /// Get the priority of the layer.
+    #[must_use]
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Get how the layer combines with the layers below it.
+    #[must_use]
+    pub fn blend(&self) -> LayerBlend {
+        if self.additive.is_some() {
+            LayerBlend::Additive
+        } else {
+            LayerBlend::Overwrite
+        }
+    }
+
+    /// Set the animation speed of the layer.
+    ///
+    /// See [`Animator::set_speed()`] for details.
+    ///
+    /// [`Animator::set_speed()`]: crate::Animator::set_speed
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed;
+    }
+
+    /// Get the animation speed of the layer.
+    #[must_use]
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Set the tweenable of the layer.
+    pub fn set_tweenable(&mut self, tween: impl Tweenable<T> + 'static) {
+        self.tweenable = Box::new(tween);
+    }
+
+    /// Get the tweenable of the layer.
+    #[must_use]
+    pub fn tweenable(&self) -> &dyn Tweenable<T> {
+        self.tweenable.as_ref()
+    }
+
+    /// Get the mutable tweenable of the layer.
+    #[must_use]
+    pub fn tweenable_mut(&mut self) -> &mut dyn Tweenable<T> {
+        self.tweenable.as_mut()
+    }
+
+    /// Stop the playback of the layer and rewind its animation.
+    pub fn stop(&mut self) {
+        self.state = AnimatorState::Paused;
+        self.tweenable.rewind();
+    }
+}
+
+/// Component to control several independent animations of another component.
+///
+/// An entity can only hold a single [`Animator<T>`] per component type `T`. A
+/// layered animator instead holds several named [`AnimatorLayer`], each with
+/// its own tweenable, playback state, and speed, which are all ticked by the
+/// same system. This allows for example to play a one-shot hit reaction on top
+/// of a breathing loop animating the same [`Transform`].
+///
+/// The layers are applied by increasing priority, each one either overwriting
+/// the fields it animates or adding its animated offset on top of the layers
+/// below it (see [`LayerBlend`]).
+///
+/// Like [`Animator<T>`], this requires a system to tick it. Unlike for
+/// [`Animator<T>`], [`TweeningPlugin`] doesn't add that system for any type by
+/// default. Add it with [`TweeningPluginBuilder::with_layered_component()`],
+/// or add [`layered_animator_system::<T>`] directly.
+///
+/// An entity can have both an [`Animator<T>`] and a layered animator for the
+/// same component, in which case the layers apply on top of the value written
+/// by the animator.
+///
+/// Unlike [`Animator<T>`], a layered animator doesn't raise any
+/// [`AnimatorEvent`]. The tweenables of its layers can still raise their own
+/// completion events and invoke their callbacks.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_tweening::{lens::*, *};
+/// # use bevy::prelude::*;
+/// # use std::time::Duration;
+/// # let breathing = Tween::new(EaseFunction::QuadraticInOut, Duration::from_secs(1),
+/// #     TransformScaleLens { start: Vec3::ONE, end: Vec3::splat(1.05) });
+/// # let hit = Tween::new(EaseFunction::QuadraticInOut, Duration::from_millis(200),
+/// #     TransformPositionLens { start: Vec3::ZERO, end: Vec3::X });
+/// let mut animator = LayeredAnimator::new()
+///     .with_layer(AnimatorLayer::new("breathing", breathing))
+///     .with_layer(AnimatorLayer::additive("hit", hit).with_priority(10));
+///
+/// // Later, slow down the breathing only
+/// animator.layer_mut("breathing").unwrap().set_speed(0.5);
+/// ```
+///
+/// [`Animator<T>`]: crate::Animator
+/// [`AnimatorEvent`]: crate::AnimatorEvent
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+/// [`TweeningPluginBuilder::with_layered_component()`]: crate::TweeningPluginBuilder::with_layered_component
+/// [`layered_animator_system::<T>`]: crate::layered_animator_system
+#[derive(Component)]
+pub struct LayeredAnimator<T: Component> {
+    layers: Vec<AnimatorLayer<T>>,
+    stale_offsets: Vec<AppliedOffset<T>>,
+    group: Option<String>,
+    time_source: Option<TimeSource>,
+}
+
+impl<T: Component> Default for LayeredAnimator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Component + std::fmt::Debug> std::fmt::Debug for LayeredAnimator<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LayeredAnimator")
+            .field(
+                "layers",
+                &self.layers.iter().map(|l| l.name()).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<T: Component> LayeredAnimator<T> {
+    /// Create a new layered animator without any layer.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            layers: vec![],
+            stale_offsets: vec![],
+            group: None,
+            time_source: None,
+        }
+    }
+
+    /// Assign the animator to a named group of [`TweeningTime`].
+    ///
+    /// The time delta of all the layers is then scaled by the time scale of
+    /// that group, in addition to the global time scale.
+    ///
+    /// [`TweeningTime`]: crate::TweeningTime
+    #[must_use]
+    pub fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Assign the animator to a named group of [`TweeningTime`].
+    ///
+    /// [`TweeningTime`]: crate::TweeningTime
+    pub fn set_group(&mut self, group: impl Into<String>) {
+        self.group = Some(group.into());
+    }
+
+    /// Remove the animator from its group of [`TweeningTime`], if any.
+    ///
+    /// [`TweeningTime`]: crate::TweeningTime
+    pub fn clear_group(&mut self) {
+        self.group = None;
+    }
+
+    /// Get the group of [`TweeningTime`] the animator is assigned to, if any.
+    ///
+    /// [`TweeningTime`]: crate::TweeningTime
+    #[must_use]
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    /// Select the source of the time delta all the layers advance by.
+    ///
+    /// By default the animator uses the [`TweeningTime::default_source()`].
+    ///
+    /// [`TweeningTime::default_source()`]: crate::TweeningTime::default_source
+    #[must_use]
+    pub fn with_time_source(mut self, source: TimeSource) -> Self {
+        self.time_source = Some(source);
+        self
+    }
+
+    /// Select the source of the time delta all the layers advance by.
+    pub fn set_time_source(&mut self, source: TimeSource) {
+        self.time_source = Some(source);
+    }
+
+    /// Revert to the [`TweeningTime::default_source()`].
+    ///
+    /// [`TweeningTime::default_source()`]: crate::TweeningTime::default_source
+    pub fn clear_time_source(&mut self) {
+        self.time_source = None;
+    }
+
+    /// Get the source of the time delta all the layers advance by, if the
+    /// animator selected one.
+    #[must_use]
+    pub fn time_source(&self) -> Option<TimeSource> {
+        self.time_source
+    }
+
+    /// Add a layer, replacing any existing layer with the same name.
+    #[must_use]
+    pub fn with_layer(mut self, layer: AnimatorLayer<T>) -> Self {
+        self.add_layer(layer);
+        self
+    }
+
+    /// Add a layer, replacing any existing layer with the same name.
+    pub fn add_layer(&mut self, layer: AnimatorLayer<T>) {
+        self.remove_layer(layer.name());
+        self.layers.push(layer);
+    }
+
+    /// Remove the layer with the given name, if any.
+    ///
+    /// The offset of an additive layer is removed from the component on the
+    /// next update.
+    pub fn remove_layer(&mut self, name: &str) -> Option<AnimatorLayer<T>> {
+        let index = self.layers.iter().position(|l| l.name() == name)?;
+        let mut layer = self.layers.remove(index);
+        if let (Some(offset), Some(additive)) = (layer.applied.take(), &layer.additive) {
+            self.stale_offsets.push(AppliedOffset {
+                offset,
+                remove: additive.remove,
+            });
+        }
+        Some(layer)
+    }
+
+    /// Get the layer with the given name, if any.
+    #[must_use]
+    pub fn layer(&self, name: &str) -> Option<&AnimatorLayer<T>> {
+        self.layers.iter().find(|l| l.name() == name)
+    }
+
+    /// Get the mutable layer with the given name, if any.
+    #[must_use]
+    pub fn layer_mut(&mut self, name: &str) -> Option<&mut AnimatorLayer<T>> {
+        self.layers.iter_mut().find(|l| l.name() == name)
+    }
+
+    /// Iterate over all the layers, in the order they're applied.
+    pub fn layers(&self) -> impl Iterator<Item = &AnimatorLayer<T>> {
+        self.layers.iter()
+    }
+
+    /// Tick all the layers, calling `tick` to tick each playing layer, and
+    /// combine them into the given target.
+    ///
+    /// The offsets added by additive layers during the previous update are
+    /// removed first, so that they don't accumulate, and so that other systems
+    /// can still modify the target in between.
+    pub(crate) fn tick_layers(
+        &mut self,
+        target: &mut dyn Targetable<T>,
+        mut tick: impl FnMut(&mut dyn Tweenable<T>, f32, &mut dyn Targetable<T>),
+    ) {
+        let target = target.target_mut();
+        for applied in self.stale_offsets.drain(..).rev() {
+            (applied.remove)(target, &applied.offset);
+        }
+        for layer in self.layers.iter_mut().rev() {
+            if let (Some(offset), Some(additive)) = (&layer.applied, &layer.additive) {
+                (additive.remove)(target, offset);
+            }
+        }
+
+        self.layers.sort_by_key(|l| l.priority);
+        for layer in &mut self.layers {
+            let playing = layer.state != AnimatorState::Paused;
+            match &layer.additive {
+                None => {
+                    if playing {
+                        tick(
+                            layer.tweenable.as_mut(),
+                            layer.speed,
+                            &mut ValueTarget(target),
+                        );
+                    }
+                }
+                Some(additive) => {
+                    if playing {
+                        let mut offset = layer.applied.take().unwrap_or_else(additive.default);
+                        tick(
+                            layer.tweenable.as_mut(),
+                            layer.speed,
+                            &mut ValueTarget(&mut offset),
+                        );
+                        layer.applied = Some(offset);
+                    }
+                    // A paused layer keeps its offset
+                    if let Some(offset) = &layer.applied {
+                        (additive.add)(target, offset);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::event::Events;
+
+    use super::*;
+    use crate::{lens::*, EaseMethod, Tween, TweenCompleted, TweenEvents};
+
+    fn make_tween(lens: impl Lens<Transform> + Send + Sync + 'static) -> Tween<Transform> {
+        Tween::new(EaseMethod::Linear, Duration::from_secs(1), lens)
+    }
+
+    fn tick(animator: &mut LayeredAnimator<Transform>, transform: &mut Transform, delta: Duration) {
+        let mut events = Events::<TweenCompleted>::default();
+        let mut events = TweenEvents::new(&mut events);
+        animator.tick_layers(&mut ValueTarget(transform), |tweenable, speed, target| {
+            tweenable.tick(
+                delta.mul_f32(speed),
+                target,
+                Entity::from_raw(0),
+                &mut events,
+            );
+        });
+    }
+
+    #[test]
+    fn layered_overwrite() {
+        let mut animator = LayeredAnimator::new()
+            .with_layer(
+                AnimatorLayer::new(
+                    "high",
+                    make_tween(TransformPositionLens {
+                        start: Vec3::ZERO,
+                        end: Vec3::splat(4.),
+                    }),
+                )
+                .with_priority(10)
+                .with_speed(0.5),
+            )
+            .with_layer(AnimatorLayer::new(
+                "low",
+                make_tween(TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::splat(3.),
+                }),
+            ));
+        assert_eq!(animator.layer("high").unwrap().priority(), 10);
+        assert_eq!(
+            animator.layer("low").unwrap().blend(),
+            LayerBlend::Overwrite
+        );
+        assert!(animator.layer("none").is_none());
+
+        let mut transform = Transform::default();
+
+        // The layer with the highest priority wins
+        tick(&mut animator, &mut transform, Duration::from_millis(500));
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        let names: Vec<_> = animator.layers().map(|l| l.name()).collect();
+        assert_eq!(names, vec!["low", "high"]);
+
+        // A paused layer doesn't write anything
+        animator.layer_mut("high").unwrap().state = AnimatorState::Paused;
+        tick(&mut animator, &mut transform, Duration::from_millis(500));
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(3.), 1e-5));
+
+        // Adding a layer with an existing name replaces it
+        animator.add_layer(AnimatorLayer::new(
+            "low",
+            make_tween(TransformScaleLens {
+                start: Vec3::ONE,
+                end: Vec3::splat(2.),
+            }),
+        ));
+        assert_eq!(animator.layers().count(), 2);
+        tick(&mut animator, &mut transform, Duration::from_millis(500));
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(1.5), 1e-5));
+    }
+
+    #[test]
+    fn layered_additive() {
+        let mut animator = LayeredAnimator::new()
+            .with_layer(AnimatorLayer::additive(
+                "bob",
+                make_tween(TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::Y,
+                }),
+            ))
+            .with_layer(AnimatorLayer::additive(
+                "pulse",
+                make_tween(TransformScaleLens {
+                    start: Vec3::ONE,
+                    end: Vec3::splat(2.),
+                }),
+            ));
+        assert_eq!(animator.layer("bob").unwrap().blend(), LayerBlend::Additive);
+
+        let mut transform = Transform::from_xyz(10., 0., 0.).with_scale(Vec3::splat(2.));
+
+        tick(&mut animator, &mut transform, Duration::from_millis(500));
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(10., 0.5, 0.), 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(3.), 1e-5));
+
+        // Offsets don't accumulate, and other changes are preserved
+        transform.translation.x = 20.;
+        tick(&mut animator, &mut transform, Duration::from_millis(250));
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(20., 0.75, 0.), 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(3.5), 1e-5));
+
+        // A paused layer keeps its offset
+        animator.layer_mut("pulse").unwrap().state = AnimatorState::Paused;
+        tick(&mut animator, &mut transform, Duration::from_millis(100));
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(3.5), 1e-5));
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(20., 0.85, 0.), 1e-5));
+
+        // Removing a layer removes its offset
+        assert!(animator.remove_layer("bob").is_some());
+        assert!(animator.remove_layer("bob").is_none());
+        tick(&mut animator, &mut transform, Duration::ZERO);
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(20., 0., 0.), 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(3.5), 1e-5));
+    }
+}
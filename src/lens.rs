@@ -31,11 +31,41 @@
 //! - [`TransformRotateZLens`]
 //! - [`TransformRotateAxisLens`]
 //!
+//! # Procedural animations
+//!
+//! Some lenses don't interpolate between two values, but instead produce a
+//! procedural motion around a base value, whose amplitude fades out over the
+//! course of the animation:
+//! - [`TransformShakeLens`] produces a noisy translation and rotation shake,
+//!   typically used for camera shake or hit reactions. The noise is entirely
+//!   determined by a seed, so animations are reproducible.
+//! - [`TransformOscillationLens`] produces a damped sine oscillation of the
+//!   translation.
+//!
+//! Those lenses sample their motion from the ratio they receive, so their
+//! frequency is expressed in number of cycles over the entire animation, and
+//! they are best used with [`EaseMethod::Linear`].
+//!
+//! # Discrete values
+//!
+//! Some fields cannot be interpolated, like booleans or enums. The generic
+//! [`DiscreteLens`] assigns one of two values to such a field depending on
+//! whether the ratio is above a given threshold. This allows for example
+//! toggling the [`Visibility`] of an entity as part of a [`Sequence`], which
+//! is what the built-in [`VisibilityLens`] does.
+//!
 //! [`rotation`]: https://docs.rs/bevy/0.9.0/bevy/transform/components/struct.Transform.html#structfield.rotation
 //! [`Transform`]: https://docs.rs/bevy/0.9.0/bevy/transform/components/struct.Transform.html
 //! [`Quat::slerp()`]: https://docs.rs/bevy/0.9.0/bevy/math/struct.Quat.html#method.slerp
+//! [`Visibility`]: https://docs.rs/bevy/0.9.0/bevy/render/view/struct.Visibility.html
+//! [`Sequence`]: crate::Sequence
+//! [`EaseMethod::Linear`]: crate::EaseMethod::Linear
 
+#[cfg(feature = "bevy_audio")]
+use bevy::audio::{AudioSink, PlaybackSettings};
 use bevy::prelude::*;
+#[cfg(feature = "bevy_sprite")]
+use bevy::sprite::Anchor;
 
 /// A lens over a subset of a component.
 ///
@@ -74,6 +104,98 @@ pub trait Lens<T> {
     fn lerp(&mut self, target: &mut T, ratio: f32);
 }
 
+/// A lens assigning one of two discrete values to a field of a component or
+/// asset.
+///
+/// The field is assigned the `start` value while the ratio is less than or
+/// equal to `threshold`, and the `end` value once the ratio is strictly
+/// greater than `threshold`. This matches the behavior of
+/// [`EaseMethod::Discrete`]. This is useful for fields which cannot be
+/// interpolated, like booleans or enums.
+///
+/// The field is selected by a function returning a mutable reference to it
+/// from the target.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// #[derive(Component)]
+/// struct Door {
+///     is_open: bool,
+/// }
+///
+/// // Open the door halfway through the animation.
+/// let tween = Tween::new(
+///     EaseMethod::Linear,
+///     Duration::from_secs(1),
+///     DiscreteLens::new(false, true, 0.5, |door: &mut Door| &mut door.is_open),
+/// );
+/// ```
+///
+/// [`EaseMethod::Discrete`]: crate::EaseMethod::Discrete
+pub struct DiscreteLens<T, V: Clone> {
+    /// Value assigned while the ratio is less than or equal to `threshold`.
+    pub start: V,
+    /// Value assigned once the ratio is greater than `threshold`.
+    pub end: V,
+    /// Ratio in \[0:1\] above which the `end` value is assigned.
+    pub threshold: f32,
+    field: fn(&mut T) -> &mut V,
+}
+
+impl<T, V: Clone> DiscreteLens<T, V> {
+    /// Create a new discrete lens over the field returned by `field`.
+    pub fn new(start: V, end: V, threshold: f32, field: fn(&mut T) -> &mut V) -> Self {
+        Self {
+            start,
+            end,
+            threshold,
+            field,
+        }
+    }
+}
+
+impl<T, V: Clone> Clone for DiscreteLens<T, V> {
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start.clone(),
+            end: self.end.clone(),
+            threshold: self.threshold,
+            field: self.field,
+        }
+    }
+}
+
+impl<T, V: Clone + std::fmt::Debug> std::fmt::Debug for DiscreteLens<T, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DiscreteLens")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("threshold", &self.threshold)
+            .finish()
+    }
+}
+
+impl<T, V: Clone> Lens<T> for DiscreteLens<T, V> {
+    fn lerp(&mut self, target: &mut T, ratio: f32) {
+        *(self.field)(target) = discrete(&self.start, &self.end, self.threshold, ratio).clone();
+    }
+}
+
+/// Select the `start` value while the ratio is less than or equal to
+/// `threshold`, and the `end` value once the ratio is strictly greater than
+/// `threshold`.
+fn discrete<'a, V>(start: &'a V, end: &'a V, threshold: f32, ratio: f32) -> &'a V {
+    if ratio > threshold {
+        end
+    } else {
+        start
+    }
+}
+
 /// A lens to manipulate the [`color`] field of a section of a [`Text`]
 /// component.
 ///
@@ -285,6 +407,146 @@ impl Lens<Transform> for TransformScaleLens {
     }
 }
 
+/// Hash a lattice point of a noise channel into a gradient value in \[-1:1\].
+fn noise_gradient(seed: u32, channel: u32, index: i32) -> f32 {
+    // Integer hash derived from the MurmurHash3 finalizer, to get a noise which is
+    // both well distributed and deterministic on all platforms.
+    let mut h = seed ^ channel.wrapping_mul(0x9E37_79B9) ^ (index as u32).wrapping_mul(0x85EB_CA6B);
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x7FEB_352D);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x846C_A68B);
+    h ^= h >> 16;
+    (h as f32 / u32::MAX as f32).mul_add(2., -1.)
+}
+
+/// Sample a 1D Perlin-style gradient noise in \[-1:1\].
+///
+/// The noise is zero at integer positions, and smoothly varies in between.
+fn noise_1d(seed: u32, channel: u32, x: f32) -> f32 {
+    let x0 = x.floor();
+    let t = x - x0;
+    let i0 = x0 as i32;
+    let g0 = noise_gradient(seed, channel, i0) * t;
+    let g1 = noise_gradient(seed, channel, i0.wrapping_add(1)) * (t - 1.);
+    // Quintic fade curve, for a C2-continuous noise
+    let fade = t * t * t * (t * (t * 6. - 15.) + 10.);
+    // The raw gradient noise is in [-0.5:0.5]
+    2. * (g1 - g0).mul_add(fade, g0)
+}
+
+/// A lens to shake a [`Transform`] component, for camera shake or hit
+/// reactions.
+///
+/// This lens offsets the [`translation`] and [`rotation`] of a [`Transform`]
+/// from their base values with a smooth gradient noise, whose amplitude decays
+/// over the course of the animation. The amplitude follows a "trauma" model:
+/// the trauma is `1 - ratio`, and the shake amplitude is the square of the
+/// trauma, which makes the shake feel more natural than a linear decay. At a
+/// ratio of `1`, the transform is back to its base value.
+///
+/// The noise is entirely determined by the `seed` value, so animating with the
+/// same seed always produces the same shake, which keeps tests and replays
+/// reproducible.
+///
+/// See the [top-level `lens` module documentation] for details on procedural
+/// lenses.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// let tween = Tween::new(
+///     EaseMethod::Linear,
+///     Duration::from_millis(500),
+///     TransformShakeLens {
+///         translation: Vec3::ZERO,
+///         rotation: Quat::IDENTITY,
+///         translation_amplitude: Vec3::new(8., 8., 0.),
+///         rotation_amplitude: Vec3::new(0., 0., 5_f32.to_radians()),
+///         frequency: 15.,
+///         seed: 42,
+///     },
+/// );
+/// ```
+///
+/// [`translation`]: https://docs.rs/bevy/0.9.0/bevy/transform/components/struct.Transform.html#structfield.translation
+/// [`rotation`]: https://docs.rs/bevy/0.9.0/bevy/transform/components/struct.Transform.html#structfield.rotation
+/// [`Transform`]: https://docs.rs/bevy/0.9.0/bevy/transform/components/struct.Transform.html
+/// [top-level `lens` module documentation]: crate::lens
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TransformShakeLens {
+    /// Base value of the translation, around which the shake occurs.
+    pub translation: Vec3,
+    /// Base value of the rotation, around which the shake occurs.
+    pub rotation: Quat,
+    /// Maximum translation offset along each axis, at full trauma.
+    pub translation_amplitude: Vec3,
+    /// Maximum rotation angle around each local axis, in radians, at full
+    /// trauma.
+    pub rotation_amplitude: Vec3,
+    /// Number of noise cycles over the entire animation.
+    pub frequency: f32,
+    /// Seed of the noise.
+    pub seed: u32,
+}
+
+impl Lens<Transform> for TransformShakeLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        let trauma = 1. - ratio.clamp(0., 1.);
+        let shake = trauma * trauma;
+        let x = ratio * self.frequency;
+        let noise = |channel| noise_1d(self.seed, channel, x) * shake;
+
+        let offset = Vec3::new(noise(0), noise(1), noise(2)) * self.translation_amplitude;
+        target.translation = self.translation + offset;
+
+        let angles = Vec3::new(noise(3), noise(4), noise(5)) * self.rotation_amplitude;
+        target.rotation =
+            self.rotation * Quat::from_euler(EulerRot::XYZ, angles.x, angles.y, angles.z);
+    }
+}
+
+/// A lens to oscillate the [`translation`] of a [`Transform`] component.
+///
+/// This lens offsets the [`translation`] of a [`Transform`] from its base value
+/// along a sine wave, whose amplitude decays exponentially over the course of
+/// the animation:
+///
+/// ```txt
+/// translation + amplitude * sin(2 * PI * frequency * ratio) * exp(-damping * ratio)
+/// ```
+///
+/// With a `damping` of zero, the oscillation keeps a constant amplitude.
+///
+/// See the [top-level `lens` module documentation] for details on procedural
+/// lenses.
+///
+/// [`translation`]: https://docs.rs/bevy/0.9.0/bevy/transform/components/struct.Transform.html#structfield.translation
+/// [`Transform`]: https://docs.rs/bevy/0.9.0/bevy/transform/components/struct.Transform.html
+/// [top-level `lens` module documentation]: crate::lens
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TransformOscillationLens {
+    /// Base value of the translation, around which the oscillation occurs.
+    pub translation: Vec3,
+    /// Amplitude of the oscillation along each axis.
+    pub amplitude: Vec3,
+    /// Number of oscillation cycles over the entire animation.
+    pub frequency: f32,
+    /// Exponential damping factor of the amplitude over the animation.
+    pub damping: f32,
+}
+
+impl Lens<Transform> for TransformOscillationLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        let wave = (std::f32::consts::TAU * self.frequency * ratio).sin();
+        let decay = (-self.damping * ratio).exp();
+        target.translation = self.translation + self.amplitude * (wave * decay);
+    }
+}
+
 /// A lens to manipulate the [`position`] field of a UI [`Style`] component.
 ///
 /// [`position`]: https://docs.rs/bevy/0.9.0/bevy/ui/struct.Style.html#structfield.position
@@ -371,108 +633,843 @@ impl Lens<Sprite> for SpriteColorLens {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use std::f32::consts::TAU;
+/// A lens to manipulate the [`custom_size`] field of a [`Sprite`] component.
+///
+/// Unlike animating the [`scale`] of the [`Transform`] of the sprite entity,
+/// animating the sprite size doesn't affect the children of that entity. This
+/// makes it well suited for squash-and-stretch effects.
+///
+/// The lens always assigns a value to the field, which becomes `Some(size)`
+/// after the first update even if it was `None` before.
+///
+/// [`custom_size`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.Sprite.html#structfield.custom_size
+/// [`Sprite`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.Sprite.html
+/// [`scale`]: https://docs.rs/bevy/0.9.0/bevy/transform/components/struct.Transform.html#structfield.scale
+/// [`Transform`]: https://docs.rs/bevy/0.9.0/bevy/transform/components/struct.Transform.html
+#[cfg(feature = "bevy_sprite")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpriteCustomSizeLens {
+    /// Start size.
+    pub start: Vec2,
+    /// End size.
+    pub end: Vec2,
+}
 
-    use super::*;
+#[cfg(feature = "bevy_sprite")]
+impl Lens<Sprite> for SpriteCustomSizeLens {
+    fn lerp(&mut self, target: &mut Sprite, ratio: f32) {
+        target.custom_size = Some(self.start.lerp(self.end, ratio));
+    }
+}
 
-    #[cfg(feature = "bevy_text")]
-    #[test]
-    fn text_color() {
-        let mut lens = TextColorLens {
-            start: Color::RED,
-            end: Color::BLUE,
-            section: 0,
-        };
-        let mut text = Text::from_section("", default());
+/// A lens to manipulate the [`flip_x`] field of a [`Sprite`] component.
+///
+/// The flip is discrete, and follows the same rules as a [`DiscreteLens`]:
+/// the field is assigned the `start` value while the ratio is less than or
+/// equal to `threshold`, and the `end` value once the ratio is strictly
+/// greater than `threshold`.
+///
+/// [`flip_x`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.Sprite.html#structfield.flip_x
+/// [`Sprite`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.Sprite.html
+#[cfg(feature = "bevy_sprite")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpriteFlipXLens {
+    /// Start value of the flip.
+    pub start: bool,
+    /// End value of the flip.
+    pub end: bool,
+    /// Ratio in \[0:1\] above which the `end` value is assigned.
+    pub threshold: f32,
+}
 
-        lens.lerp(&mut text, 0.);
-        assert_eq!(text.sections[0].style.color, Color::RED);
+#[cfg(feature = "bevy_sprite")]
+impl Lens<Sprite> for SpriteFlipXLens {
+    fn lerp(&mut self, target: &mut Sprite, ratio: f32) {
+        target.flip_x = *discrete(&self.start, &self.end, self.threshold, ratio);
+    }
+}
 
-        lens.lerp(&mut text, 1.);
-        assert_eq!(text.sections[0].style.color, Color::BLUE);
+/// A lens to manipulate the [`flip_y`] field of a [`Sprite`] component.
+///
+/// The flip is discrete, see [`SpriteFlipXLens`] for details.
+///
+/// [`flip_y`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.Sprite.html#structfield.flip_y
+/// [`Sprite`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.Sprite.html
+#[cfg(feature = "bevy_sprite")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpriteFlipYLens {
+    /// Start value of the flip.
+    pub start: bool,
+    /// End value of the flip.
+    pub end: bool,
+    /// Ratio in \[0:1\] above which the `end` value is assigned.
+    pub threshold: f32,
+}
 
-        lens.lerp(&mut text, 0.3);
-        assert_eq!(text.sections[0].style.color, Color::rgba(0.7, 0., 0.3, 1.0));
+#[cfg(feature = "bevy_sprite")]
+impl Lens<Sprite> for SpriteFlipYLens {
+    fn lerp(&mut self, target: &mut Sprite, ratio: f32) {
+        target.flip_y = *discrete(&self.start, &self.end, self.threshold, ratio);
     }
+}
 
-    #[test]
-    fn transform_position() {
-        let mut lens = TransformPositionLens {
-            start: Vec3::ZERO,
-            end: Vec3::new(1., 2., -4.),
-        };
-        let mut transform = Transform::default();
+/// A lens to manipulate the [`anchor`] field of a [`Sprite`] component.
+///
+/// The lens interpolates between two anchor offsets, and always assigns an
+/// [`Anchor::Custom`] value. The offsets of the predefined anchors can be
+/// obtained with [`Anchor::as_vec()`], for example to animate from
+/// [`Anchor::Center`] to [`Anchor::BottomCenter`].
+///
+/// [`anchor`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.Sprite.html#structfield.anchor
+/// [`Sprite`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.Sprite.html
+/// [`Anchor::Custom`]: https://docs.rs/bevy/0.9.0/bevy/sprite/enum.Anchor.html#variant.Custom
+/// [`Anchor::as_vec()`]: https://docs.rs/bevy/0.9.0/bevy/sprite/enum.Anchor.html#method.as_vec
+/// [`Anchor::Center`]: https://docs.rs/bevy/0.9.0/bevy/sprite/enum.Anchor.html#variant.Center
+/// [`Anchor::BottomCenter`]: https://docs.rs/bevy/0.9.0/bevy/sprite/enum.Anchor.html#variant.BottomCenter
+#[cfg(feature = "bevy_sprite")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpriteAnchorLens {
+    /// Start anchor offset.
+    pub start: Vec2,
+    /// End anchor offset.
+    pub end: Vec2,
+}
 
-        lens.lerp(&mut transform, 0.);
-        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
-        assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
-        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+#[cfg(feature = "bevy_sprite")]
+impl Lens<Sprite> for SpriteAnchorLens {
+    fn lerp(&mut self, target: &mut Sprite, ratio: f32) {
+        target.anchor = Anchor::Custom(self.start.lerp(self.end, ratio));
+    }
+}
 
-        lens.lerp(&mut transform, 1.);
-        assert!(transform
-            .translation
-            .abs_diff_eq(Vec3::new(1., 2., -4.), 1e-5));
-        assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
-        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+/// A lens to manipulate the [`index`] field of a [`TextureAtlasSprite`]
+/// component.
+///
+/// This lens steps through all the frames of a sprite sheet in the inclusive
+/// range `start..=end`, each frame being displayed for an equal fraction of the
+/// animation. This allows simple flipbook animations, which can be looped with
+/// [`RepeatCount`] and played back and forth with
+/// [`RepeatStrategy::MirroredRepeat`]. The `end` frame index can be smaller
+/// than the `start` one to play the frames in reverse order.
+///
+/// The frame index is derived from the eased ratio, so an easing other than
+/// [`EaseMethod::Linear`] changes how long each frame is displayed.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// // Play frames 0 to 7 of a sprite sheet in a loop, at 10 FPS.
+/// let tween = Tween::new(
+///     EaseMethod::Linear,
+///     Duration::from_millis(800),
+///     TextureAtlasSpriteIndexLens { start: 0, end: 7 },
+/// )
+/// .with_repeat_count(RepeatCount::Infinite);
+/// let animator = Animator::<TextureAtlasSprite>::new(tween);
+/// ```
+///
+/// [`index`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.TextureAtlasSprite.html#structfield.index
+/// [`TextureAtlasSprite`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.TextureAtlasSprite.html
+/// [`RepeatCount`]: crate::RepeatCount
+/// [`RepeatStrategy::MirroredRepeat`]: crate::RepeatStrategy::MirroredRepeat
+/// [`EaseMethod::Linear`]: crate::EaseMethod::Linear
+#[cfg(feature = "bevy_sprite")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TextureAtlasSpriteIndexLens {
+    /// Index of the first frame, displayed at ratio `0`.
+    pub start: usize,
+    /// Index of the last frame (inclusive), displayed at ratio `1`.
+    pub end: usize,
+}
 
-        lens.lerp(&mut transform, 0.3);
-        assert!(transform
-            .translation
-            .abs_diff_eq(Vec3::new(0.3, 0.6, -1.2), 1e-5));
-        assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
-        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+#[cfg(feature = "bevy_sprite")]
+impl Lens<TextureAtlasSprite> for TextureAtlasSpriteIndexLens {
+    fn lerp(&mut self, target: &mut TextureAtlasSprite, ratio: f32) {
+        let count = self.start.abs_diff(self.end);
+        // Split [0:1] into (count + 1) equal intervals, one per frame, with the
+        // last frame also covering the ratio value 1 itself.
+        let offset = ((ratio.clamp(0., 1.) * (count + 1) as f32) as usize).min(count);
+        target.index = if self.end >= self.start {
+            self.start + offset
+        } else {
+            self.start - offset
+        };
     }
+}
 
-    #[test]
-    fn transform_rotation() {
-        let mut lens = TransformRotationLens {
-            start: Quat::IDENTITY,
-            end: Quat::from_rotation_z(100_f32.to_radians()),
-        };
-        let mut transform = Transform::default();
+/// A lens to manipulate the [`base_color`] field of a [`StandardMaterial`]
+/// asset.
+///
+/// [`base_color`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.StandardMaterial.html#structfield.base_color
+/// [`StandardMaterial`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.StandardMaterial.html
+#[cfg(feature = "bevy_pbr")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StandardMaterialBaseColorLens {
+    /// Start color.
+    pub start: Color,
+    /// End color.
+    pub end: Color,
+}
 
-        lens.lerp(&mut transform, 0.);
-        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
-        assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
-        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+#[cfg(feature = "bevy_pbr")]
+impl Lens<StandardMaterial> for StandardMaterialBaseColorLens {
+    fn lerp(&mut self, target: &mut StandardMaterial, ratio: f32) {
+        // Note: Add<f32> for Color affects alpha, but not Mul<f32>. So use Vec4 for
+        // consistency.
+        let start: Vec4 = self.start.into();
+        let end: Vec4 = self.end.into();
+        let value = start.lerp(end, ratio);
+        target.base_color = value.into();
+    }
+}
 
-        lens.lerp(&mut transform, 1.);
-        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
-        assert!(transform
-            .rotation
-            .abs_diff_eq(Quat::from_rotation_z(100_f32.to_radians()), 1e-5));
-        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+/// A lens to manipulate the [`emissive`] field of a [`StandardMaterial`]
+/// asset.
+///
+/// [`emissive`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.StandardMaterial.html#structfield.emissive
+/// [`StandardMaterial`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.StandardMaterial.html
+#[cfg(feature = "bevy_pbr")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StandardMaterialEmissiveLens {
+    /// Start color.
+    pub start: Color,
+    /// End color.
+    pub end: Color,
+}
 
-        lens.lerp(&mut transform, 0.3);
-        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
-        assert!(transform
-            .rotation
-            .abs_diff_eq(Quat::from_rotation_z(30_f32.to_radians()), 1e-5));
-        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+#[cfg(feature = "bevy_pbr")]
+impl Lens<StandardMaterial> for StandardMaterialEmissiveLens {
+    fn lerp(&mut self, target: &mut StandardMaterial, ratio: f32) {
+        // Note: Add<f32> for Color affects alpha, but not Mul<f32>. So use Vec4 for
+        // consistency.
+        let start: Vec4 = self.start.into();
+        let end: Vec4 = self.end.into();
+        let value = start.lerp(end, ratio);
+        target.emissive = value.into();
     }
+}
 
-    #[test]
-    fn transform_rotate_x() {
-        let mut lens = TransformRotateXLens {
-            start: 0.,
-            end: 1440_f32.to_radians(), // 4 turns
-        };
-        let mut transform = Transform::default();
+/// A lens to manipulate the [`metallic`] field of a [`StandardMaterial`]
+/// asset.
+///
+/// [`metallic`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.StandardMaterial.html#structfield.metallic
+/// [`StandardMaterial`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.StandardMaterial.html
+#[cfg(feature = "bevy_pbr")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StandardMaterialMetallicLens {
+    /// Start value of the metallic factor.
+    pub start: f32,
+    /// End value of the metallic factor.
+    pub end: f32,
+}
 
-        for (index, ratio) in [0., 0.25, 0.5, 0.75, 1.].iter().enumerate() {
-            lens.lerp(&mut transform, *ratio);
-            assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
-            if index == 1 || index == 3 {
-                // For odd-numbered turns, the opposite Quat is produced. This is equivalent in
-                // terms of rotation to the IDENTITY one, but numerically the w component is not
-                // the same so would fail an equality test.
-                assert!(transform
-                    .rotation
-                    .abs_diff_eq(Quat::from_xyzw(0., 0., 0., -1.), 1e-5));
-            } else {
-                assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+#[cfg(feature = "bevy_pbr")]
+impl Lens<StandardMaterial> for StandardMaterialMetallicLens {
+    fn lerp(&mut self, target: &mut StandardMaterial, ratio: f32) {
+        target.metallic = (self.end - self.start).mul_add(ratio, self.start);
+    }
+}
+
+/// A lens to manipulate the [`perceptual_roughness`] field of a
+/// [`StandardMaterial`] asset.
+///
+/// [`perceptual_roughness`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.StandardMaterial.html#structfield.perceptual_roughness
+/// [`StandardMaterial`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.StandardMaterial.html
+#[cfg(feature = "bevy_pbr")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StandardMaterialPerceptualRoughnessLens {
+    /// Start value of the perceptual roughness.
+    pub start: f32,
+    /// End value of the perceptual roughness.
+    pub end: f32,
+}
+
+#[cfg(feature = "bevy_pbr")]
+impl Lens<StandardMaterial> for StandardMaterialPerceptualRoughnessLens {
+    fn lerp(&mut self, target: &mut StandardMaterial, ratio: f32) {
+        target.perceptual_roughness = (self.end - self.start).mul_add(ratio, self.start);
+    }
+}
+
+/// A lens to manipulate the alpha component of the [`base_color`] field of a
+/// [`StandardMaterial`] asset.
+///
+/// This only animates the alpha value, leaving the RGB components of the color
+/// untouched. Note that the material needs an [`alpha_mode`] supporting
+/// transparency, like [`AlphaMode::Blend`], for the change to be visible.
+///
+/// [`base_color`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.StandardMaterial.html#structfield.base_color
+/// [`StandardMaterial`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.StandardMaterial.html
+/// [`alpha_mode`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.StandardMaterial.html#structfield.alpha_mode
+/// [`AlphaMode::Blend`]: https://docs.rs/bevy/0.9.0/bevy/pbr/enum.AlphaMode.html#variant.Blend
+#[cfg(feature = "bevy_pbr")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StandardMaterialAlphaLens {
+    /// Start alpha value.
+    pub start: f32,
+    /// End alpha value.
+    pub end: f32,
+}
+
+#[cfg(feature = "bevy_pbr")]
+impl Lens<StandardMaterial> for StandardMaterialAlphaLens {
+    fn lerp(&mut self, target: &mut StandardMaterial, ratio: f32) {
+        let alpha = (self.end - self.start).mul_add(ratio, self.start);
+        target.base_color.set_a(alpha);
+    }
+}
+
+/// A lens to manipulate the [`color`] field of a [`PointLight`] component.
+///
+/// [`color`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.PointLight.html#structfield.color
+/// [`PointLight`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.PointLight.html
+#[cfg(feature = "bevy_pbr")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PointLightColorLens {
+    /// Start color.
+    pub start: Color,
+    /// End color.
+    pub end: Color,
+}
+
+#[cfg(feature = "bevy_pbr")]
+impl Lens<PointLight> for PointLightColorLens {
+    fn lerp(&mut self, target: &mut PointLight, ratio: f32) {
+        // Note: Add<f32> for Color affects alpha, but not Mul<f32>. So use Vec4 for
+        // consistency.
+        let start: Vec4 = self.start.into();
+        let end: Vec4 = self.end.into();
+        let value = start.lerp(end, ratio);
+        target.color = value.into();
+    }
+}
+
+/// A lens to manipulate the [`intensity`] field of a [`PointLight`] component.
+///
+/// [`intensity`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.PointLight.html#structfield.intensity
+/// [`PointLight`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.PointLight.html
+#[cfg(feature = "bevy_pbr")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PointLightIntensityLens {
+    /// Start value of the luminous power, in lumens.
+    pub start: f32,
+    /// End value of the luminous power, in lumens.
+    pub end: f32,
+}
+
+#[cfg(feature = "bevy_pbr")]
+impl Lens<PointLight> for PointLightIntensityLens {
+    fn lerp(&mut self, target: &mut PointLight, ratio: f32) {
+        target.intensity = (self.end - self.start).mul_add(ratio, self.start);
+    }
+}
+
+/// A lens to manipulate the [`range`] field of a [`PointLight`] component.
+///
+/// [`range`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.PointLight.html#structfield.range
+/// [`PointLight`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.PointLight.html
+#[cfg(feature = "bevy_pbr")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PointLightRangeLens {
+    /// Start value of the range, in world units.
+    pub start: f32,
+    /// End value of the range, in world units.
+    pub end: f32,
+}
+
+#[cfg(feature = "bevy_pbr")]
+impl Lens<PointLight> for PointLightRangeLens {
+    fn lerp(&mut self, target: &mut PointLight, ratio: f32) {
+        target.range = (self.end - self.start).mul_add(ratio, self.start);
+    }
+}
+
+/// A lens to manipulate the [`color`] field of a [`SpotLight`] component.
+///
+/// [`color`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.SpotLight.html#structfield.color
+/// [`SpotLight`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.SpotLight.html
+#[cfg(feature = "bevy_pbr")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpotLightColorLens {
+    /// Start color.
+    pub start: Color,
+    /// End color.
+    pub end: Color,
+}
+
+#[cfg(feature = "bevy_pbr")]
+impl Lens<SpotLight> for SpotLightColorLens {
+    fn lerp(&mut self, target: &mut SpotLight, ratio: f32) {
+        // Note: Add<f32> for Color affects alpha, but not Mul<f32>. So use Vec4 for
+        // consistency.
+        let start: Vec4 = self.start.into();
+        let end: Vec4 = self.end.into();
+        let value = start.lerp(end, ratio);
+        target.color = value.into();
+    }
+}
+
+/// A lens to manipulate the [`intensity`] field of a [`SpotLight`] component.
+///
+/// [`intensity`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.SpotLight.html#structfield.intensity
+/// [`SpotLight`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.SpotLight.html
+#[cfg(feature = "bevy_pbr")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpotLightIntensityLens {
+    /// Start value of the luminous power, in lumens.
+    pub start: f32,
+    /// End value of the luminous power, in lumens.
+    pub end: f32,
+}
+
+#[cfg(feature = "bevy_pbr")]
+impl Lens<SpotLight> for SpotLightIntensityLens {
+    fn lerp(&mut self, target: &mut SpotLight, ratio: f32) {
+        target.intensity = (self.end - self.start).mul_add(ratio, self.start);
+    }
+}
+
+/// A lens to manipulate the [`range`] field of a [`SpotLight`] component.
+///
+/// [`range`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.SpotLight.html#structfield.range
+/// [`SpotLight`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.SpotLight.html
+#[cfg(feature = "bevy_pbr")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpotLightRangeLens {
+    /// Start value of the range, in world units.
+    pub start: f32,
+    /// End value of the range, in world units.
+    pub end: f32,
+}
+
+#[cfg(feature = "bevy_pbr")]
+impl Lens<SpotLight> for SpotLightRangeLens {
+    fn lerp(&mut self, target: &mut SpotLight, ratio: f32) {
+        target.range = (self.end - self.start).mul_add(ratio, self.start);
+    }
+}
+
+/// A lens to manipulate the [`color`] field of a [`DirectionalLight`] component.
+///
+/// [`color`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.DirectionalLight.html#structfield.color
+/// [`DirectionalLight`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.DirectionalLight.html
+#[cfg(feature = "bevy_pbr")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DirectionalLightColorLens {
+    /// Start color.
+    pub start: Color,
+    /// End color.
+    pub end: Color,
+}
+
+#[cfg(feature = "bevy_pbr")]
+impl Lens<DirectionalLight> for DirectionalLightColorLens {
+    fn lerp(&mut self, target: &mut DirectionalLight, ratio: f32) {
+        // Note: Add<f32> for Color affects alpha, but not Mul<f32>. So use Vec4 for
+        // consistency.
+        let start: Vec4 = self.start.into();
+        let end: Vec4 = self.end.into();
+        let value = start.lerp(end, ratio);
+        target.color = value.into();
+    }
+}
+
+/// A lens to manipulate the [`illuminance`] field of a [`DirectionalLight`] component.
+///
+/// Directional lights have no range nor intensity; their brightness is
+/// controlled by this illuminance value instead.
+///
+/// [`illuminance`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.DirectionalLight.html#structfield.illuminance
+/// [`DirectionalLight`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.DirectionalLight.html
+#[cfg(feature = "bevy_pbr")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DirectionalLightIlluminanceLens {
+    /// Start value of the illuminance, in lux.
+    pub start: f32,
+    /// End value of the illuminance, in lux.
+    pub end: f32,
+}
+
+#[cfg(feature = "bevy_pbr")]
+impl Lens<DirectionalLight> for DirectionalLightIlluminanceLens {
+    fn lerp(&mut self, target: &mut DirectionalLight, ratio: f32) {
+        target.illuminance = (self.end - self.start).mul_add(ratio, self.start);
+    }
+}
+
+/// A lens to manipulate the [`is_visible`] field of a [`Visibility`]
+/// component.
+///
+/// The visibility is discrete: the field is assigned the `start` value while
+/// the ratio is less than or equal to `threshold`, and the `end` value once the
+/// ratio is strictly greater than `threshold`. See [`DiscreteLens`] for
+/// details.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// // Show an entity after 1 second, then hide it again 2 seconds later.
+/// let seq = Delay::new(Duration::from_secs(1))
+///     .then(Tween::new(
+///         EaseMethod::Linear,
+///         Duration::from_secs(2),
+///         VisibilityLens {
+///             start: true,
+///             end: false,
+///             threshold: 0.999,
+///         },
+///     ));
+/// let animator = Animator::<Visibility>::new(seq);
+/// ```
+///
+/// [`is_visible`]: https://docs.rs/bevy/0.9.0/bevy/render/view/struct.Visibility.html#structfield.is_visible
+/// [`Visibility`]: https://docs.rs/bevy/0.9.0/bevy/render/view/struct.Visibility.html
+#[cfg(feature = "bevy_render")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VisibilityLens {
+    /// Start visibility.
+    pub start: bool,
+    /// End visibility.
+    pub end: bool,
+    /// Ratio in \[0:1\] above which the `end` value is assigned.
+    pub threshold: f32,
+}
+
+#[cfg(feature = "bevy_render")]
+impl Lens<Visibility> for VisibilityLens {
+    fn lerp(&mut self, target: &mut Visibility, ratio: f32) {
+        target.is_visible = *discrete(&self.start, &self.end, self.threshold, ratio);
+    }
+}
+
+/// A lens to manipulate the [`scale`] field of an [`OrthographicProjection`]
+/// camera component.
+///
+/// This is the typical way to zoom a 2D camera. A scale smaller than `1.0`
+/// zooms in, while a scale larger than `1.0` zooms out.
+///
+/// The lens can also animate a [`Projection`] component, which is used by
+/// default by the 3D camera bundle. In that case, the lens only affects
+/// [`Projection::Orthographic`] values, and leaves other variants unchanged.
+///
+/// [`scale`]: https://docs.rs/bevy/0.9.0/bevy/render/camera/struct.OrthographicProjection.html#structfield.scale
+/// [`OrthographicProjection`]: https://docs.rs/bevy/0.9.0/bevy/render/camera/struct.OrthographicProjection.html
+/// [`Projection`]: https://docs.rs/bevy/0.9.0/bevy/render/camera/enum.Projection.html
+/// [`Projection::Orthographic`]: https://docs.rs/bevy/0.9.0/bevy/render/camera/enum.Projection.html#variant.Orthographic
+#[cfg(feature = "bevy_render")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct OrthographicProjectionScaleLens {
+    /// Start value of the scale.
+    pub start: f32,
+    /// End value of the scale.
+    pub end: f32,
+}
+
+#[cfg(feature = "bevy_render")]
+impl Lens<OrthographicProjection> for OrthographicProjectionScaleLens {
+    fn lerp(&mut self, target: &mut OrthographicProjection, ratio: f32) {
+        target.scale = (self.end - self.start).mul_add(ratio, self.start);
+    }
+}
+
+#[cfg(feature = "bevy_render")]
+impl Lens<Projection> for OrthographicProjectionScaleLens {
+    fn lerp(&mut self, target: &mut Projection, ratio: f32) {
+        if let Projection::Orthographic(projection) = target {
+            self.lerp(projection, ratio);
+        }
+    }
+}
+
+/// A lens to manipulate the [`fov`] field of a [`PerspectiveProjection`]
+/// camera component.
+///
+/// The lens can also animate a [`Projection`] component, which is used by
+/// default by the 3D camera bundle. In that case, the lens only affects
+/// [`Projection::Perspective`] values, and leaves other variants unchanged.
+///
+/// [`fov`]: https://docs.rs/bevy/0.9.0/bevy/render/camera/struct.PerspectiveProjection.html#structfield.fov
+/// [`PerspectiveProjection`]: https://docs.rs/bevy/0.9.0/bevy/render/camera/struct.PerspectiveProjection.html
+/// [`Projection`]: https://docs.rs/bevy/0.9.0/bevy/render/camera/enum.Projection.html
+/// [`Projection::Perspective`]: https://docs.rs/bevy/0.9.0/bevy/render/camera/enum.Projection.html#variant.Perspective
+#[cfg(feature = "bevy_render")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PerspectiveProjectionFovLens {
+    /// Start value of the vertical field of view, in radians.
+    pub start: f32,
+    /// End value of the vertical field of view, in radians.
+    pub end: f32,
+}
+
+#[cfg(feature = "bevy_render")]
+impl Lens<PerspectiveProjection> for PerspectiveProjectionFovLens {
+    fn lerp(&mut self, target: &mut PerspectiveProjection, ratio: f32) {
+        target.fov = (self.end - self.start).mul_add(ratio, self.start);
+    }
+}
+
+#[cfg(feature = "bevy_render")]
+impl Lens<Projection> for PerspectiveProjectionFovLens {
+    fn lerp(&mut self, target: &mut Projection, ratio: f32) {
+        if let Projection::Perspective(projection) = target {
+            self.lerp(projection, ratio);
+        }
+    }
+}
+
+/// A lens to manipulate the volume of an [`AudioSink`] asset.
+///
+/// This is typically used to fade in and out music and sounds. The sink of a
+/// sound being played is an asset, so the lens is used with an
+/// [`AssetAnimator`] referencing the handle returned by [`Audio::play()`]:
+///
+/// ```no_run
+/// # use bevy::{audio::AudioSink, prelude::*};
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// fn play_music(
+///     mut commands: Commands,
+///     asset_server: Res<AssetServer>,
+///     audio: Res<Audio>,
+///     audio_sinks: Res<Assets<AudioSink>>,
+/// ) {
+///     let weak_handle = audio.play(asset_server.load("music.ogg"));
+///     // Upgrade to a strong handle to keep the sink alive
+///     let handle = audio_sinks.get_handle(weak_handle);
+///     let fade_in = Tween::new(
+///         EaseMethod::Linear,
+///         Duration::from_secs(2),
+///         AudioSinkVolumeLens {
+///             start: 0.,
+///             end: 1.,
+///         },
+///     );
+///     commands.spawn(AssetAnimator::new(handle, fade_in));
+/// }
+/// ```
+///
+/// Note that the [`AudioSink`] asset only exists once Bevy started playing
+/// the sound, which generally happens one frame after [`Audio::play()`] is
+/// called. The animator doesn't tick until the asset exists, so the tween
+/// always starts from its beginning.
+///
+/// The lens also applies to the [`PlaybackSettings`] a sound is played with.
+/// This allows starting the sound with the same volume the tween starts from,
+/// to avoid it being briefly audible at full volume:
+///
+/// ```
+/// # use bevy::audio::PlaybackSettings;
+/// # use bevy_tweening::{lens::*, *};
+/// let mut lens = AudioSinkVolumeLens {
+///     start: 0.,
+///     end: 1.,
+/// };
+/// let mut settings = PlaybackSettings::LOOP;
+/// lens.lerp(&mut settings, 0.);
+/// assert_eq!(settings.volume, 0.);
+/// ```
+///
+/// [`AudioSink`]: https://docs.rs/bevy/0.9.0/bevy/audio/struct.AudioSink.html
+/// [`AssetAnimator`]: crate::AssetAnimator
+/// [`Audio::play()`]: https://docs.rs/bevy/0.9.0/bevy/audio/struct.Audio.html#method.play
+/// [`PlaybackSettings`]: https://docs.rs/bevy/0.9.0/bevy/audio/struct.PlaybackSettings.html
+#[cfg(feature = "bevy_audio")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AudioSinkVolumeLens {
+    /// Start volume.
+    pub start: f32,
+    /// End volume.
+    pub end: f32,
+}
+
+#[cfg(feature = "bevy_audio")]
+impl Lens<AudioSink> for AudioSinkVolumeLens {
+    fn lerp(&mut self, target: &mut AudioSink, ratio: f32) {
+        let value = (self.end - self.start).mul_add(ratio, self.start);
+        target.set_volume(value);
+    }
+}
+
+#[cfg(feature = "bevy_audio")]
+impl Lens<PlaybackSettings> for AudioSinkVolumeLens {
+    fn lerp(&mut self, target: &mut PlaybackSettings, ratio: f32) {
+        target.volume = (self.end - self.start).mul_add(ratio, self.start);
+    }
+}
+
+/// A lens to manipulate the playback speed of an [`AudioSink`] asset.
+///
+/// See [`AudioSinkVolumeLens`] for details on animating an audio sink. Like
+/// that lens, this one also applies to the [`PlaybackSettings`] of a sound.
+///
+/// [`AudioSink`]: https://docs.rs/bevy/0.9.0/bevy/audio/struct.AudioSink.html
+/// [`PlaybackSettings`]: https://docs.rs/bevy/0.9.0/bevy/audio/struct.PlaybackSettings.html
+#[cfg(feature = "bevy_audio")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AudioSinkSpeedLens {
+    /// Start speed.
+    pub start: f32,
+    /// End speed.
+    pub end: f32,
+}
+
+#[cfg(feature = "bevy_audio")]
+impl Lens<AudioSink> for AudioSinkSpeedLens {
+    fn lerp(&mut self, target: &mut AudioSink, ratio: f32) {
+        let value = (self.end - self.start).mul_add(ratio, self.start);
+        target.set_speed(value);
+    }
+}
+
+#[cfg(feature = "bevy_audio")]
+impl Lens<PlaybackSettings> for AudioSinkSpeedLens {
+    fn lerp(&mut self, target: &mut PlaybackSettings, ratio: f32) {
+        target.speed = (self.end - self.start).mul_add(ratio, self.start);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::TAU;
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[cfg(feature = "bevy_text")]
+    #[test]
+    fn text_color() {
+        let mut lens = TextColorLens {
+            start: Color::RED,
+            end: Color::BLUE,
+            section: 0,
+        };
+        let mut text = Text::from_section("", default());
+
+        lens.lerp(&mut text, 0.);
+        assert_eq!(text.sections[0].style.color, Color::RED);
+
+        lens.lerp(&mut text, 1.);
+        assert_eq!(text.sections[0].style.color, Color::BLUE);
+
+        lens.lerp(&mut text, 0.3);
+        assert_eq!(text.sections[0].style.color, Color::rgba(0.7, 0., 0.3, 1.0));
+    }
+
+    #[test]
+    fn discrete() {
+        let mut lens = DiscreteLens::new(Vec3::ZERO, Vec3::ONE, 0.4, |t: &mut Transform| {
+            &mut t.translation
+        });
+        let mut transform = Transform::default();
+
+        for (ratio, value) in [
+            (0., Vec3::ZERO),
+            (0.3, Vec3::ZERO),
+            (0.4, Vec3::ZERO),
+            (0.41, Vec3::ONE),
+            (1., Vec3::ONE),
+            (0.2, Vec3::ZERO),
+        ] {
+            lens.lerp(&mut transform, ratio);
+            assert_eq!(transform.translation, value);
+            assert_eq!(transform.rotation, Quat::IDENTITY);
+            assert_eq!(transform.scale, Vec3::ONE);
+        }
+
+        // impl Debug
+        let debug_string = format!("{:?}", lens);
+        assert_eq!(
+            debug_string,
+            format!(
+                "DiscreteLens {{ start: {:?}, end: {:?}, threshold: 0.4 }}",
+                Vec3::ZERO,
+                Vec3::ONE
+            )
+        );
+    }
+
+    #[test]
+    fn transform_position() {
+        let mut lens = TransformPositionLens {
+            start: Vec3::ZERO,
+            end: Vec3::new(1., 2., -4.),
+        };
+        let mut transform = Transform::default();
+
+        lens.lerp(&mut transform, 0.);
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+        assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+
+        lens.lerp(&mut transform, 1.);
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(1., 2., -4.), 1e-5));
+        assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+
+        lens.lerp(&mut transform, 0.3);
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(0.3, 0.6, -1.2), 1e-5));
+        assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+    }
+
+    #[test]
+    fn transform_rotation() {
+        let mut lens = TransformRotationLens {
+            start: Quat::IDENTITY,
+            end: Quat::from_rotation_z(100_f32.to_radians()),
+        };
+        let mut transform = Transform::default();
+
+        lens.lerp(&mut transform, 0.);
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+        assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+
+        lens.lerp(&mut transform, 1.);
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(100_f32.to_radians()), 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+
+        lens.lerp(&mut transform, 0.3);
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(30_f32.to_radians()), 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+    }
+
+    #[test]
+    fn transform_rotate_x() {
+        let mut lens = TransformRotateXLens {
+            start: 0.,
+            end: 1440_f32.to_radians(), // 4 turns
+        };
+        let mut transform = Transform::default();
+
+        for (index, ratio) in [0., 0.25, 0.5, 0.75, 1.].iter().enumerate() {
+            lens.lerp(&mut transform, *ratio);
+            assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+            if index == 1 || index == 3 {
+                // For odd-numbered turns, the opposite Quat is produced. This is equivalent in
+                // terms of rotation to the IDENTITY one, but numerically the w component is not
+                // the same so would fail an equality test.
+                assert!(transform
+                    .rotation
+                    .abs_diff_eq(Quat::from_xyzw(0., 0., 0., -1.), 1e-5));
+            } else {
+                assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
             }
             assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
         }
@@ -607,6 +1604,124 @@ mod tests {
         assert!(transform.scale.abs_diff_eq(Vec3::new(0.3, 0.6, -1.2), 1e-5));
     }
 
+    #[test]
+    fn noise() {
+        for seed in [0, 1, 42, u32::MAX] {
+            for channel in 0..6 {
+                // Zero at lattice points
+                for x in [-3., 0., 1., 17.] {
+                    assert_approx_eq!(noise_1d(seed, channel, x), 0.);
+                }
+                // Bounded and deterministic
+                for i in 0..100 {
+                    let x = i as f32 * 0.173;
+                    let n = noise_1d(seed, channel, x);
+                    assert!((-1. ..=1.).contains(&n));
+                    assert_eq!(n, noise_1d(seed, channel, x));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transform_shake() {
+        let base = Transform::from_xyz(1., 2., 3.).with_rotation(Quat::from_rotation_z(0.5));
+        let make_lens = |seed| TransformShakeLens {
+            translation: base.translation,
+            rotation: base.rotation,
+            translation_amplitude: Vec3::new(1., 2., 0.),
+            rotation_amplitude: Vec3::new(0., 0., 0.2),
+            frequency: 10.,
+            seed,
+        };
+        let mut lens = make_lens(42);
+        let mut transform = Transform::default();
+
+        // No offset at both ends
+        for ratio in [0., 1.] {
+            lens.lerp(&mut transform, ratio);
+            assert!(transform.translation.abs_diff_eq(base.translation, 1e-5));
+            assert!(transform.rotation.abs_diff_eq(base.rotation, 1e-5));
+            assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+        }
+
+        let mut other_lens = make_lens(43);
+        let mut other_transform = Transform::default();
+        let mut max_offset = Vec3::ZERO;
+        let mut any_different = false;
+        for i in 0..100 {
+            let ratio = i as f32 / 100.;
+            lens.lerp(&mut transform, ratio);
+
+            // Offset bounded by the decaying amplitude, only along animated axes
+            let shake = (1. - ratio) * (1. - ratio);
+            let offset = transform.translation - base.translation;
+            assert!(offset.x.abs() <= shake + 1e-5);
+            assert!(offset.y.abs() <= 2. * shake + 1e-5);
+            assert_approx_eq!(offset.z, 0.);
+            max_offset = max_offset.max(offset.abs());
+            // Rotation offset only around the animated Z axis
+            let delta = base.rotation.inverse() * transform.rotation;
+            assert_approx_eq!(delta.x, 0.);
+            assert_approx_eq!(delta.y, 0.);
+
+            // Deterministic for a given seed
+            let mut same = Transform::default();
+            make_lens(42).lerp(&mut same, ratio);
+            assert_eq!(same, transform);
+
+            // Different for a different seed
+            other_lens.lerp(&mut other_transform, ratio);
+            any_different |= !other_transform
+                .translation
+                .abs_diff_eq(transform.translation, 1e-5);
+        }
+        assert!(max_offset.x > 0.1);
+        assert!(max_offset.y > 0.1);
+        assert!(any_different);
+    }
+
+    #[test]
+    fn transform_oscillation() {
+        let mut lens = TransformOscillationLens {
+            translation: Vec3::new(1., 2., 3.),
+            amplitude: Vec3::new(0., 2., 0.),
+            frequency: 2.,
+            damping: 0.,
+        };
+        let mut transform = Transform::default();
+
+        for (ratio, y) in [
+            (0., 2.),
+            (0.125, 4.),
+            (0.25, 2.),
+            (0.375, 0.),
+            (0.5, 2.),
+            (0.625, 4.),
+            (1., 2.),
+        ] {
+            lens.lerp(&mut transform, ratio);
+            assert!(transform
+                .translation
+                .abs_diff_eq(Vec3::new(1., y, 3.), 1e-5));
+            assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+            assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+        }
+
+        // Damping
+        lens.damping = 2.;
+        lens.lerp(&mut transform, 0.125);
+        let expected = 2. + 2. * (-0.25_f32).exp();
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(1., expected, 3.), 1e-5));
+        lens.lerp(&mut transform, 0.625);
+        let expected = 2. + 2. * (-1.25_f32).exp();
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(1., expected, 3.), 1e-5));
+    }
+
     #[cfg(feature = "bevy_ui")]
     #[test]
     fn ui_position() {
@@ -667,6 +1782,120 @@ mod tests {
         assert_eq!(mat.color, Color::rgba(0.7, 0., 0.3, 1.0));
     }
 
+    #[cfg(feature = "bevy_sprite")]
+    #[test]
+    fn sprite_custom_size() {
+        let mut lens = SpriteCustomSizeLens {
+            start: Vec2::new(16., 32.),
+            end: Vec2::new(32., 16.),
+        };
+        let mut sprite = Sprite::default();
+        assert!(sprite.custom_size.is_none());
+
+        lens.lerp(&mut sprite, 0.);
+        assert_eq!(sprite.custom_size, Some(Vec2::new(16., 32.)));
+
+        lens.lerp(&mut sprite, 1.);
+        assert_eq!(sprite.custom_size, Some(Vec2::new(32., 16.)));
+
+        lens.lerp(&mut sprite, 0.25);
+        assert!(sprite
+            .custom_size
+            .unwrap()
+            .abs_diff_eq(Vec2::new(20., 28.), 1e-5));
+    }
+
+    #[cfg(feature = "bevy_sprite")]
+    #[test]
+    fn sprite_flip() {
+        let mut lens_x = SpriteFlipXLens {
+            start: false,
+            end: true,
+            threshold: 0.5,
+        };
+        let mut lens_y = SpriteFlipYLens {
+            start: true,
+            end: false,
+            threshold: 0.2,
+        };
+        let mut sprite = Sprite::default();
+
+        for (ratio, flip_x, flip_y) in [
+            (0., false, true),
+            (0.2, false, true),
+            (0.3, false, false),
+            (0.5, false, false),
+            (0.51, true, false),
+            (1., true, false),
+        ] {
+            lens_x.lerp(&mut sprite, ratio);
+            lens_y.lerp(&mut sprite, ratio);
+            assert_eq!(sprite.flip_x, flip_x);
+            assert_eq!(sprite.flip_y, flip_y);
+        }
+    }
+
+    #[cfg(feature = "bevy_sprite")]
+    #[test]
+    fn sprite_anchor() {
+        let mut lens = SpriteAnchorLens {
+            start: Anchor::Center.as_vec(),
+            end: Anchor::BottomCenter.as_vec(),
+        };
+        let mut sprite = Sprite::default();
+
+        lens.lerp(&mut sprite, 0.);
+        assert!(matches!(sprite.anchor, Anchor::Custom(v) if v.abs_diff_eq(Vec2::ZERO, 1e-5)));
+
+        lens.lerp(&mut sprite, 1.);
+        assert!(
+            matches!(sprite.anchor, Anchor::Custom(v) if v.abs_diff_eq(Vec2::new(0., -0.5), 1e-5))
+        );
+
+        lens.lerp(&mut sprite, 0.3);
+        assert!(
+            matches!(sprite.anchor, Anchor::Custom(v) if v.abs_diff_eq(Vec2::new(0., -0.15), 1e-5))
+        );
+    }
+
+    #[cfg(feature = "bevy_sprite")]
+    #[test]
+    fn texture_atlas_sprite_index() {
+        let mut lens = TextureAtlasSpriteIndexLens { start: 2, end: 5 };
+        let mut sprite = TextureAtlasSprite::new(0);
+
+        for (ratio, index) in [
+            (0., 2),
+            (0.2, 2),
+            (0.25, 3),
+            (0.49, 3),
+            (0.5, 4),
+            (0.74, 4),
+            (0.75, 5),
+            (0.99, 5),
+            (1., 5),
+        ] {
+            lens.lerp(&mut sprite, ratio);
+            assert_eq!(sprite.index, index);
+        }
+
+        // Reversed range
+        let mut lens = TextureAtlasSpriteIndexLens { start: 5, end: 2 };
+        lens.lerp(&mut sprite, 0.);
+        assert_eq!(sprite.index, 5);
+        lens.lerp(&mut sprite, 0.3);
+        assert_eq!(sprite.index, 4);
+        lens.lerp(&mut sprite, 1.);
+        assert_eq!(sprite.index, 2);
+
+        // Single frame
+        let mut lens = TextureAtlasSpriteIndexLens { start: 3, end: 3 };
+        for ratio in [0., 0.5, 1.] {
+            lens.lerp(&mut sprite, ratio);
+            assert_eq!(sprite.index, 3);
+        }
+    }
+
     #[cfg(feature = "bevy_sprite")]
     #[test]
     fn sprite_color() {
@@ -688,4 +1917,288 @@ mod tests {
         lens.lerp(&mut sprite, 0.3);
         assert_eq!(sprite.color, Color::rgba(0.7, 0., 0.3, 1.0));
     }
+
+    #[cfg(feature = "bevy_pbr")]
+    #[test]
+    fn standard_material_base_color() {
+        let mut lens = StandardMaterialBaseColorLens {
+            start: Color::RED,
+            end: Color::BLUE,
+        };
+        let mut mat = StandardMaterial::default();
+
+        lens.lerp(&mut mat, 0.);
+        assert_eq!(mat.base_color, Color::RED);
+
+        lens.lerp(&mut mat, 1.);
+        assert_eq!(mat.base_color, Color::BLUE);
+
+        lens.lerp(&mut mat, 0.3);
+        assert_eq!(mat.base_color, Color::rgba(0.7, 0., 0.3, 1.0));
+    }
+
+    #[cfg(feature = "bevy_pbr")]
+    #[test]
+    fn standard_material_emissive() {
+        let mut lens = StandardMaterialEmissiveLens {
+            start: Color::BLACK,
+            end: Color::WHITE,
+        };
+        let mut mat = StandardMaterial::default();
+
+        lens.lerp(&mut mat, 0.);
+        assert_eq!(mat.emissive, Color::BLACK);
+
+        lens.lerp(&mut mat, 1.);
+        assert_eq!(mat.emissive, Color::WHITE);
+
+        lens.lerp(&mut mat, 0.3);
+        assert_eq!(mat.emissive, Color::rgba(0.3, 0.3, 0.3, 1.0));
+    }
+
+    #[cfg(feature = "bevy_pbr")]
+    #[test]
+    fn standard_material_metallic() {
+        let mut lens = StandardMaterialMetallicLens { start: 0., end: 1. };
+        let mut mat = StandardMaterial::default();
+
+        lens.lerp(&mut mat, 0.);
+        assert_approx_eq!(mat.metallic, 0.);
+
+        lens.lerp(&mut mat, 1.);
+        assert_approx_eq!(mat.metallic, 1.);
+
+        lens.lerp(&mut mat, 0.3);
+        assert_approx_eq!(mat.metallic, 0.3);
+    }
+
+    #[cfg(feature = "bevy_pbr")]
+    #[test]
+    fn standard_material_perceptual_roughness() {
+        let mut lens = StandardMaterialPerceptualRoughnessLens {
+            start: 0.1,
+            end: 0.9,
+        };
+        let mut mat = StandardMaterial::default();
+
+        lens.lerp(&mut mat, 0.);
+        assert_approx_eq!(mat.perceptual_roughness, 0.1);
+
+        lens.lerp(&mut mat, 1.);
+        assert_approx_eq!(mat.perceptual_roughness, 0.9);
+
+        lens.lerp(&mut mat, 0.5);
+        assert_approx_eq!(mat.perceptual_roughness, 0.5);
+    }
+
+    #[cfg(feature = "bevy_pbr")]
+    #[test]
+    fn standard_material_alpha() {
+        let mut lens = StandardMaterialAlphaLens { start: 1., end: 0. };
+        let mut mat = StandardMaterial {
+            base_color: Color::RED,
+            ..default()
+        };
+
+        lens.lerp(&mut mat, 0.);
+        assert_eq!(mat.base_color, Color::RED);
+
+        lens.lerp(&mut mat, 1.);
+        assert_eq!(mat.base_color, Color::rgba(1., 0., 0., 0.));
+
+        lens.lerp(&mut mat, 0.3);
+        assert_eq!(mat.base_color, Color::rgba(1., 0., 0., 0.7));
+    }
+
+    #[cfg(feature = "bevy_pbr")]
+    #[test]
+    fn point_light() {
+        let mut light = PointLight::default();
+
+        let mut lens = PointLightColorLens {
+            start: Color::RED,
+            end: Color::BLUE,
+        };
+        lens.lerp(&mut light, 0.3);
+        assert_eq!(light.color, Color::rgba(0.7, 0., 0.3, 1.0));
+
+        let mut lens = PointLightIntensityLens {
+            start: 100.,
+            end: 800.,
+        };
+        lens.lerp(&mut light, 0.);
+        assert_approx_eq!(light.intensity, 100.);
+        lens.lerp(&mut light, 1.);
+        assert_approx_eq!(light.intensity, 800.);
+        lens.lerp(&mut light, 0.5);
+        assert_approx_eq!(light.intensity, 450.);
+
+        let mut lens = PointLightRangeLens { start: 2., end: 4. };
+        lens.lerp(&mut light, 0.25);
+        assert_approx_eq!(light.range, 2.5);
+    }
+
+    #[cfg(feature = "bevy_pbr")]
+    #[test]
+    fn spot_light() {
+        let mut light = SpotLight::default();
+
+        let mut lens = SpotLightColorLens {
+            start: Color::RED,
+            end: Color::BLUE,
+        };
+        lens.lerp(&mut light, 0.3);
+        assert_eq!(light.color, Color::rgba(0.7, 0., 0.3, 1.0));
+
+        let mut lens = SpotLightIntensityLens {
+            start: 100.,
+            end: 800.,
+        };
+        lens.lerp(&mut light, 0.5);
+        assert_approx_eq!(light.intensity, 450.);
+
+        let mut lens = SpotLightRangeLens { start: 2., end: 4. };
+        lens.lerp(&mut light, 0.25);
+        assert_approx_eq!(light.range, 2.5);
+    }
+
+    #[cfg(feature = "bevy_pbr")]
+    #[test]
+    fn directional_light() {
+        let mut light = DirectionalLight::default();
+
+        let mut lens = DirectionalLightColorLens {
+            start: Color::RED,
+            end: Color::BLUE,
+        };
+        lens.lerp(&mut light, 0.3);
+        assert_eq!(light.color, Color::rgba(0.7, 0., 0.3, 1.0));
+
+        let mut lens = DirectionalLightIlluminanceLens {
+            start: 1000.,
+            end: 2000.,
+        };
+        lens.lerp(&mut light, 0.);
+        assert_approx_eq!(light.illuminance, 1000.);
+        lens.lerp(&mut light, 1.);
+        assert_approx_eq!(light.illuminance, 2000.);
+        lens.lerp(&mut light, 0.3);
+        assert_approx_eq!(light.illuminance, 1300.);
+    }
+
+    #[cfg(feature = "bevy_render")]
+    #[test]
+    fn visibility() {
+        let mut lens = VisibilityLens {
+            start: false,
+            end: true,
+            threshold: 0.5,
+        };
+        let mut visibility = Visibility::VISIBLE;
+
+        lens.lerp(&mut visibility, 0.);
+        assert!(!visibility.is_visible);
+
+        lens.lerp(&mut visibility, 0.5);
+        assert!(!visibility.is_visible);
+
+        lens.lerp(&mut visibility, 0.6);
+        assert!(visibility.is_visible);
+
+        lens.lerp(&mut visibility, 1.);
+        assert!(visibility.is_visible);
+    }
+
+    #[cfg(feature = "bevy_render")]
+    #[test]
+    fn orthographic_projection_scale() {
+        let mut lens = OrthographicProjectionScaleLens {
+            start: 1.,
+            end: 0.5,
+        };
+        let mut projection = OrthographicProjection::default();
+
+        lens.lerp(&mut projection, 0.);
+        assert_approx_eq!(projection.scale, 1.);
+        lens.lerp(&mut projection, 1.);
+        assert_approx_eq!(projection.scale, 0.5);
+        lens.lerp(&mut projection, 0.2);
+        assert_approx_eq!(projection.scale, 0.9);
+
+        let mut projection = Projection::Orthographic(default());
+        lens.lerp(&mut projection, 0.2);
+        assert!(
+            matches!(projection, Projection::Orthographic(p) if abs_diff_eq(p.scale, 0.9, 1e-5))
+        );
+
+        // Other variants are left untouched
+        let mut projection = Projection::Perspective(default());
+        lens.lerp(&mut projection, 0.2);
+        assert!(
+            matches!(projection, Projection::Perspective(p) if abs_diff_eq(p.fov, PerspectiveProjection::default().fov, 1e-5))
+        );
+    }
+
+    #[cfg(feature = "bevy_render")]
+    #[test]
+    fn perspective_projection_fov() {
+        let mut lens = PerspectiveProjectionFovLens {
+            start: 0.8,
+            end: 1.2,
+        };
+        let mut projection = PerspectiveProjection::default();
+
+        lens.lerp(&mut projection, 0.);
+        assert_approx_eq!(projection.fov, 0.8);
+        lens.lerp(&mut projection, 1.);
+        assert_approx_eq!(projection.fov, 1.2);
+        lens.lerp(&mut projection, 0.25);
+        assert_approx_eq!(projection.fov, 0.9);
+
+        let mut projection = Projection::Perspective(default());
+        lens.lerp(&mut projection, 0.25);
+        assert!(matches!(projection, Projection::Perspective(p) if abs_diff_eq(p.fov, 0.9, 1e-5)));
+
+        // Other variants are left untouched
+        let mut projection = Projection::Orthographic(default());
+        lens.lerp(&mut projection, 0.25);
+        assert!(
+            matches!(projection, Projection::Orthographic(p) if abs_diff_eq(p.scale, 1., 1e-5))
+        );
+    }
+
+    #[cfg(feature = "bevy_audio")]
+    #[test]
+    fn audio_sink_volume() {
+        let mut lens = AudioSinkVolumeLens { start: 0., end: 2. };
+        let mut settings = PlaybackSettings::LOOP;
+
+        lens.lerp(&mut settings, 0.);
+        assert_approx_eq!(settings.volume, 0.);
+        lens.lerp(&mut settings, 1.);
+        assert_approx_eq!(settings.volume, 2.);
+        lens.lerp(&mut settings, 0.3);
+        assert_approx_eq!(settings.volume, 0.6);
+        assert!(settings.repeat);
+        assert_approx_eq!(settings.speed, 1.);
+    }
+
+    #[cfg(feature = "bevy_audio")]
+    #[test]
+    fn audio_sink_speed() {
+        let mut lens = AudioSinkSpeedLens {
+            start: 0.5,
+            end: 1.5,
+        };
+        let mut settings = PlaybackSettings::ONCE;
+
+        lens.lerp(&mut settings, 0.);
+        assert_approx_eq!(settings.speed, 0.5);
+        lens.lerp(&mut settings, 1.);
+        assert_approx_eq!(settings.speed, 1.5);
+        lens.lerp(&mut settings, 0.3);
+        assert_approx_eq!(settings.speed, 0.8);
+        assert!(!settings.repeat);
+        assert_approx_eq!(settings.volume, 1.);
+    }
 }
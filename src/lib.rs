@@ -74,6 +74,10 @@
 //! - [`Sequence`] - A series of tweenables executing in series, one after the
 //!   other.
 //! - [`Tracks`] - A collection of tweenables executing in parallel.
+//! - [`Timeline`] - A collection of tweenables placed at arbitrary times,
+//!   possibly overlapping, with named labels.
+//! - [`Marked`] - A wrapper around any tweenable, notifying [`Marker`] at
+//!   arbitrary points of its iterations.
 //! - [`Delay`] - A time delay. This doesn't animate anything.
 //!
 //! ## Chaining animations
@@ -125,6 +129,11 @@
 //! components it animates. This limits the proliferation of generic types when
 //! animating e.g. both the position and rotation of an entity.
 //!
+//! Since an entity can only hold one [`Animator`] per component type, several
+//! independent animations of the same component, like a breathing loop and a
+//! one-shot hit reaction, are instead played by the layers of a
+//! [`LayeredAnimator`].
+//!
 //! ## Assets animation
 //!
 //! Assets are animated in a similar way to component, via the [`AssetAnimator`]
@@ -152,7 +161,7 @@
 //! [`Sprite`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.Sprite.html
 //! [`Transform`]: https://docs.rs/bevy/0.9.0/bevy/transform/components/struct.Transform.html
 
-use std::time::Duration;
+use std::{any::TypeId, collections::VecDeque, time::Duration};
 
 #[cfg(feature = "bevy_asset")]
 use bevy::asset::Asset;
@@ -160,19 +169,29 @@ use bevy::prelude::*;
 use interpolation::Ease as IEase;
 pub use interpolation::{EaseFunction, Lerp};
 
+pub use layered::{AdditiveBlend, AnimatorLayer, LayerBlend, LayeredAnimator};
 pub use lens::Lens;
 #[cfg(feature = "bevy_asset")]
 pub use plugin::asset_animator_system;
-pub use plugin::{component_animator_system, AnimationSystem, TweeningPlugin};
+pub use plugin::{
+    clock_time_system, component_animator_system, fixed_time_system, layered_animator_system,
+    tweening_time_system, AnimationSystem, TweenEventPlugin, TweeningPlugin, TweeningPluginBuilder,
+};
+pub use time::{TimeSource, TweeningClock, TweeningTime};
 pub use tweenable::{
-    BoxedTweenable, Delay, Dummy, Sequence, Targetable, TotalDuration, Tracks, Tween,
-    TweenCompleted, TweenState, Tweenable,
+    BoxedTweenable, DeferredCallback, Delay, Dummy, Marked, Marker, MarkerCallback, MarkerReached,
+    Sequence, Targetable, Timeline, TimelinePosition, TotalDuration, Tracks, Tween, TweenCompleted,
+    TweenEvents, TweenState, Tweenable,
 };
 
+mod layered;
 pub mod lens;
 mod plugin;
+mod time;
 mod tweenable;
 
+use tweenable::ValueTarget;
+
 #[cfg(test)]
 mod test_utils;
 
@@ -209,9 +228,10 @@ impl From<Duration> for RepeatCount {
 /// [`RepeatCount`].
 ///
 /// Only applicable when [`RepeatCount`] is greater than the animation duration.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum RepeatStrategy {
     /// Reset the animation back to its starting position.
+    #[default]
     Repeat,
     /// Follow a ping-pong pattern, changing the direction each time an endpoint
     /// is reached.
@@ -223,27 +243,16 @@ pub enum RepeatStrategy {
     MirroredRepeat,
 }
 
-impl Default for RepeatStrategy {
-    fn default() -> Self {
-        Self::Repeat
-    }
-}
-
 /// Playback state of an animator.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AnimatorState {
     /// The animation is playing. This is the default state.
+    #[default]
     Playing,
     /// The animation is paused in its current state.
     Paused,
 }
 
-impl Default for AnimatorState {
-    fn default() -> Self {
-        Self::Playing
-    }
-}
-
 impl std::ops::Not for AnimatorState {
     type Output = Self;
 
@@ -255,12 +264,172 @@ impl std::ops::Not for AnimatorState {
     }
 }
 
+/// Kind of an [`AnimatorEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimatorEventKind {
+    /// The animation started playing from its start, on its first tick after
+    /// being created, rewound, or replaced.
+    ///
+    /// This is only raised when playing forward. Rewinding back to the start
+    /// with a negative speed doesn't raise it.
+    Started,
+    /// The animation completed an iteration and started a new one, with the
+    /// given index. This is not raised when completing the last iteration.
+    ///
+    /// When playing backward with a negative speed, this is raised each time
+    /// the animation moves back into the previous iteration, with the index of
+    /// that iteration.
+    Looped {
+        /// Index of the iteration which just started, counting from 0.
+        iteration: u32,
+    },
+    /// The playback direction changed while ticking, as happens on each
+    /// iteration with [`RepeatStrategy::MirroredRepeat`], whether playing
+    /// forward or backward.
+    DirectionChanged {
+        /// The new playback direction.
+        direction: TweeningDirection,
+    },
+    /// The animator was paused.
+    Paused,
+    /// The animator was resumed after being paused.
+    Resumed,
+    /// The tweenable of the animator was replaced with
+    /// [`Animator::set_tweenable()`].
+    TweenableReplaced,
+}
+
+/// Event raised when the playback of an [`Animator`] or [`AssetAnimator`]
+/// changes.
+///
+/// Unlike [`TweenCompleted`], which is raised by individual tweenables when
+/// configured to do so, this event is always raised by the animator systems
+/// for the top-level tweenable of each animator, during the update following
+/// the change.
+///
+/// When several changes happen during the same update, the events are raised
+/// in the order of the [`AnimatorEventKind`] variants: tweenable replaced,
+/// paused or resumed, then started, looped, and direction changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnimatorEvent {
+    /// The [`Entity`] the animator is attached to.
+    pub entity: Entity,
+    /// The type of the component or asset animated, to distinguish between
+    /// several animators on the same entity.
+    pub target: TypeId,
+    /// The kind of change.
+    pub kind: AnimatorEventKind,
+}
+
+/// What an animator does once its animation completed.
+///
+/// This is applied by the animator systems through [`Commands`] when the
+/// tweenable of the animator returns [`TweenState::Completed`] while playing
+/// forward, which never happens for an animation repeating infinitely. Rewinding
+/// an animation back to its start with a negative speed doesn't apply it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompletionPolicy {
+    /// Keep the animator and its entity. This is the default.
+    #[default]
+    Keep,
+    /// Remove the animator component from its entity.
+    RemoveAnimator,
+    /// Despawn the entity of the animator.
+    Despawn,
+    /// Despawn the entity of the animator and all its descendants.
+    DespawnRecursive,
+}
+
+/// How an animation enqueued on an animator interrupts the current one.
+///
+/// See [`Animator::enqueue_with()`]. To instead smoothly blend the current
+/// animation into the enqueued one, see [`Animator::enqueue_blended()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interruption {
+    /// Start the animation once the current one and all the animations already
+    /// queued completed. This is the default.
+    #[default]
+    FinishCurrent,
+    /// Drop the current animation and all the queued ones, and start the
+    /// animation immediately.
+    CancelCurrent,
+}
+
+/// Values which can be blended with each other, to smoothly transition from an
+/// animation to another.
+///
+/// While blending, lenses don't need to be aware of it. The outgoing tweenable
+/// writes into a copy of the target, then the value written into the target by
+/// the incoming tweenable is blended with it. Only the fields covered by the
+/// blend are affected, so any other field animated by the incoming tweenable,
+/// like the [`index`] of a [`TextureAtlasSprite`], keeps the value it wrote.
+/// See [`Animator::set_tweenable_blended()`].
+///
+/// [`index`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.TextureAtlasSprite.html#structfield.index
+/// [`TextureAtlasSprite`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.TextureAtlasSprite.html
+pub trait Blend: Clone + Send + Sync + 'static {
+    /// Blend this value toward `other`, by a `ratio` from 0 (unchanged) to 1
+    /// (equal to `other`).
+    ///
+    /// Fields which can't be blended are left unchanged.
+    fn blend(&mut self, other: &Self, ratio: f32);
+}
+
+impl Blend for Transform {
+    fn blend(&mut self, other: &Self, ratio: f32) {
+        self.translation = self.translation.lerp(other.translation, ratio);
+        self.rotation = self.rotation.slerp(other.rotation, ratio);
+        self.scale = self.scale.lerp(other.scale, ratio);
+    }
+}
+
+/// Blend a color toward another one, by a `ratio` from 0 to 1.
+#[cfg(feature = "bevy_sprite")]
+fn blend_color(color: &mut Color, other: Color, ratio: f32) {
+    // Note: Add<f32> for Color affects alpha, but not Mul<f32>. So use Vec4 for
+    // consistency.
+    let start: Vec4 = (*color).into();
+    let end: Vec4 = other.into();
+    *color = start.lerp(end, ratio).into();
+}
+
+#[cfg(feature = "bevy_sprite")]
+impl Blend for Sprite {
+    fn blend(&mut self, other: &Self, ratio: f32) {
+        blend_color(&mut self.color, other.color, ratio);
+    }
+}
+
+#[cfg(feature = "bevy_sprite")]
+impl Blend for TextureAtlasSprite {
+    fn blend(&mut self, other: &Self, ratio: f32) {
+        blend_color(&mut self.color, other.color, ratio);
+    }
+}
+
+#[cfg(all(feature = "bevy_sprite", feature = "bevy_asset"))]
+impl Blend for ColorMaterial {
+    fn blend(&mut self, other: &Self, ratio: f32) {
+        blend_color(&mut self.color, other.color, ratio);
+    }
+}
+
+/// Outgoing animation of an animator, being blended into the current one.
+struct BlendOut<T> {
+    tweenable: BoxedTweenable<T>,
+    elapsed: Duration,
+    duration: Duration,
+    clone: fn(&T) -> T,
+    blend: fn(&mut T, &T, f32),
+}
+
 /// Describe how eased value should be computed.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Default)]
 pub enum EaseMethod {
     /// Follow `EaseFunction`.
     EaseFunction(EaseFunction),
     /// Linear interpolation, with no function.
+    #[default]
     Linear,
     /// Discrete interpolation, eased value will jump from start to end when
     /// stepping over the discrete limit.
@@ -287,12 +456,6 @@ impl EaseMethod {
     }
 }
 
-impl Default for EaseMethod {
-    fn default() -> Self {
-        Self::Linear
-    }
-}
-
 impl From<EaseFunction> for EaseMethod {
     fn from(ease_function: EaseFunction) -> Self {
         Self::EaseFunction(ease_function)
@@ -311,13 +474,14 @@ impl From<EaseFunction> for EaseMethod {
 ///
 /// For all but [`RepeatStrategy::MirroredRepeat`] this is always
 /// [`TweeningDirection::Forward`], unless manually configured with
-/// [`Tween::set_direction()`] in which case the value is constant equal to the
+/// [`Tweenable::set_direction()`] in which case the value is constant equal to the
 /// value set. When using [`RepeatStrategy::MirroredRepeat`], this is either
 /// forward (from start to end; ping) or backward (from end to start; pong),
 /// depending on the current iteration of the loop.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TweeningDirection {
     /// Animation playing from start to end.
+    #[default]
     Forward,
     /// Animation playing from end to start, in reverse.
     Backward,
@@ -337,12 +501,6 @@ impl TweeningDirection {
     }
 }
 
-impl Default for TweeningDirection {
-    fn default() -> Self {
-        Self::Forward
-    }
-}
-
 impl std::ops::Not for TweeningDirection {
     type Output = Self;
 
@@ -360,9 +518,98 @@ macro_rules! animator_impl {
         #[must_use]
         pub fn with_state(mut self, state: AnimatorState) -> Self {
             self.state = state;
+            self.last_state = state;
+            self
+        }
+
+        /// Set what the animator does once its animation completed.
+        ///
+        /// This is useful for fire-and-forget effects, which can despawn their
+        /// entity without the need for a separate cleanup system. See
+        /// [`CompletionPolicy`] for details.
+        #[must_use]
+        pub fn with_completion_policy(mut self, policy: CompletionPolicy) -> Self {
+            self.completion_policy = policy;
+            self
+        }
+
+        /// Set what the animator does once its animation completed.
+        ///
+        /// See [`with_completion_policy()`] for details.
+        ///
+        /// [`with_completion_policy()`]: Animator::with_completion_policy
+        pub fn set_completion_policy(&mut self, policy: CompletionPolicy) {
+            self.completion_policy = policy;
+        }
+
+        /// Get what the animator does once its animation completed.
+        #[must_use]
+        pub fn completion_policy(&self) -> CompletionPolicy {
+            self.completion_policy
+        }
+
+        /// Assign the animator to a named group of [`TweeningTime`].
+        ///
+        /// The time delta of the animator is then scaled by the time scale of that
+        /// group, in addition to the global time scale, and the animator doesn't
+        /// tick while the group is paused. By default an animator doesn't belong
+        /// to any group, and is only affected by the global controls.
+        #[must_use]
+        pub fn with_group(mut self, group: impl Into<String>) -> Self {
+            self.group = Some(group.into());
+            self
+        }
+
+        /// Assign the animator to a named group of [`TweeningTime`].
+        ///
+        /// See [`with_group()`] for details.
+        ///
+        /// [`with_group()`]: Animator::with_group
+        pub fn set_group(&mut self, group: impl Into<String>) {
+            self.group = Some(group.into());
+        }
+
+        /// Remove the animator from its group of [`TweeningTime`], if any.
+        pub fn clear_group(&mut self) {
+            self.group = None;
+        }
+
+        /// Get the group of [`TweeningTime`] the animator is assigned to, if any.
+        #[must_use]
+        pub fn group(&self) -> Option<&str> {
+            self.group.as_deref()
+        }
+
+        /// Select the source of the time delta the animator advances by.
+        ///
+        /// By default an animator uses the [`TweeningTime::default_source()`].
+        #[must_use]
+        pub fn with_time_source(mut self, source: TimeSource) -> Self {
+            self.time_source = Some(source);
             self
         }
 
+        /// Select the source of the time delta the animator advances by.
+        ///
+        /// See [`with_time_source()`] for details.
+        ///
+        /// [`with_time_source()`]: Animator::with_time_source
+        pub fn set_time_source(&mut self, source: TimeSource) {
+            self.time_source = Some(source);
+        }
+
+        /// Revert to the [`TweeningTime::default_source()`].
+        pub fn clear_time_source(&mut self) {
+            self.time_source = None;
+        }
+
+        /// Get the source of the time delta the animator advances by, if it
+        /// selected one.
+        #[must_use]
+        pub fn time_source(&self) -> Option<TimeSource> {
+            self.time_source
+        }
+
         /// Set the initial speed of the animator. See [`Animator::set_speed`] for
         /// details.
         #[must_use]
@@ -375,6 +622,14 @@ macro_rules! animator_impl {
         ///
         /// A speed of 2 means the animation will run twice as fast while a speed of 0.1
         /// will result in a 10x slowed animation.
+        ///
+        /// A negative speed runs the animation backward in time, rewinding it toward
+        /// its start with [`Tweenable::tick_backward()`], for example for scrubbing
+        /// or rewind mechanics. Rewinding doesn't raise any [`TweenCompleted`] event,
+        /// and stops once the animation reached its start. To instead play the
+        /// animation reversed from its current point, see [`reverse()`].
+        ///
+        /// [`reverse()`]: Animator::reverse
         pub fn set_speed(&mut self, speed: f32) {
             self.speed = speed;
         }
@@ -389,8 +644,111 @@ macro_rules! animator_impl {
         }
 
         /// Set the top-level tweenable item this animator controls.
+        ///
+        /// This raises an [`AnimatorEventKind::TweenableReplaced`] event on the next
+        /// update.
         pub fn set_tweenable(&mut self, tween: impl Tweenable<T> + 'static) {
             self.tweenable = Box::new(tween);
+            self.tweenable_replaced = true;
+        }
+
+        /// Append a tweenable to play once the current one completed.
+        ///
+        /// Several tweenables can be enqueued, and play one after the other. The
+        /// next tweenable starts on the same update the previous one completed,
+        /// with the time left over in that update, so there's no hitch between
+        /// animations. Each switch raises an [`AnimatorEventKind::TweenableReplaced`]
+        /// event. The queue doesn't advance while the animator plays backward, and
+        /// never advances past a tweenable repeating infinitely.
+        ///
+        /// The [`CompletionPolicy`] of the animator is only applied once all the
+        /// queued tweenables completed.
+        pub fn enqueue(&mut self, tween: impl Tweenable<T> + 'static) {
+            self.queue.push_back(Box::new(tween));
+        }
+
+        /// Append a tweenable, with the given interruption of the current one.
+        ///
+        /// See [`Interruption`] for details.
+        pub fn enqueue_with(
+            &mut self,
+            tween: impl Tweenable<T> + 'static,
+            interruption: Interruption,
+        ) {
+            match interruption {
+                Interruption::FinishCurrent => self.enqueue(tween),
+                Interruption::CancelCurrent => {
+                    self.queue.clear();
+                    self.blend_out = None;
+                    self.set_tweenable(tween);
+                }
+            }
+        }
+
+        /// Replace the current tweenable, blending from it into the given one.
+        ///
+        /// This drops all the queued tweenables, then behaves like
+        /// [`set_tweenable_blended()`]. A zero duration cancels the current
+        /// tweenable like [`Interruption::CancelCurrent`].
+        ///
+        /// [`set_tweenable_blended()`]: Animator::set_tweenable_blended
+        pub fn enqueue_blended(&mut self, tween: impl Tweenable<T> + 'static, duration: Duration)
+        where
+            T: Blend,
+        {
+            self.queue.clear();
+            self.set_tweenable_blended(tween, duration);
+        }
+
+        /// Get the number of tweenables queued after the current one.
+        #[must_use]
+        pub fn queued(&self) -> usize {
+            self.queue.len()
+        }
+
+        /// Drop all the tweenables queued after the current one.
+        pub fn clear_queue(&mut self) {
+            self.queue.clear();
+        }
+
+        /// Is the animator currently blending from a previous tweenable?
+        ///
+        /// See [`set_tweenable_blended()`].
+        ///
+        /// [`set_tweenable_blended()`]: Animator::set_tweenable_blended
+        #[must_use]
+        pub fn is_blending(&self) -> bool {
+            self.blend_out.is_some()
+        }
+
+        /// Set the top-level tweenable item this animator controls, blending from
+        /// the current one during the given duration.
+        ///
+        /// During `duration` both the outgoing and the new tweenables tick, and the
+        /// target is blended from the value produced by the former to the value
+        /// produced by the latter, which avoids any pop when switching for example
+        /// between two looping animations. The queued tweenables are kept. If a
+        /// blend was already in progress, its outgoing tweenable is dropped, and
+        /// the new blend starts from the current one. A zero duration behaves like
+        /// [`set_tweenable()`].
+        ///
+        /// [`set_tweenable()`]: Animator::set_tweenable
+        pub fn set_tweenable_blended(
+            &mut self,
+            tween: impl Tweenable<T> + 'static,
+            duration: Duration,
+        ) where
+            T: Blend,
+        {
+            let outgoing = std::mem::replace(&mut self.tweenable, Box::new(tween));
+            self.tweenable_replaced = true;
+            self.blend_out = (!duration.is_zero()).then(|| BlendOut {
+                tweenable: outgoing,
+                elapsed: Duration::ZERO,
+                duration,
+                clone: T::clone,
+                blend: T::blend,
+            });
         }
 
         /// Get the top-level tweenable this animator is currently controlling.
@@ -414,6 +772,36 @@ macro_rules! animator_impl {
             self.tweenable_mut().rewind();
         }
 
+        /// Reverse the playback direction of the animation in place.
+        ///
+        /// The elapsed time is mirrored, so that the target state doesn't change,
+        /// and the animation plays back from its current point toward the state it
+        /// started from. This allows for example an interrupted hover-in animation
+        /// to smoothly play back out. Reversing a completed animation plays it back
+        /// from its end.
+        ///
+        /// For an animation repeating infinitely, only the elapsed time inside the
+        /// current iteration is mirrored. On an iteration boundary, the animation is
+        /// placed right before the end of that iteration instead of the start of the
+        /// next one, which would sample the opposite endpoint.
+        pub fn reverse(&mut self) {
+            let tweenable = self.tweenable_mut();
+            let direction = tweenable.direction();
+            let elapsed = tweenable.elapsed();
+            let elapsed = match tweenable.total_duration() {
+                TotalDuration::Finite(total_duration) => total_duration.saturating_sub(elapsed),
+                TotalDuration::Infinite => {
+                    let duration = tweenable.duration().as_nanos();
+                    let local = elapsed.as_nanos().checked_rem(duration).unwrap_or(0);
+                    let start = elapsed - Duration::from_nanos(local as u64);
+                    let mirrored = (duration - local).min(duration.saturating_sub(1));
+                    start + Duration::from_nanos(mirrored as u64)
+                }
+            };
+            tweenable.set_elapsed(elapsed);
+            tweenable.set_direction(!direction);
+        }
+
         /// Has the animation finished?
         ///
         /// This is a convenient function for checking if the progress of the tweenable is equal to 1.
@@ -422,6 +810,118 @@ macro_rules! animator_impl {
             self.tweenable().progress() == 1.0
         }
 
+        /// Check if the animator changed since the last update, and needs to raise
+        /// some [`AnimatorEvent`].
+        fn has_changes(&self) -> bool {
+            self.tweenable_replaced || self.state != self.last_state
+        }
+
+        /// Raise the [`AnimatorEvent`] for the changes since the last update.
+        fn notify_changes(&mut self, entity: Entity, events: &mut Events<AnimatorEvent>) {
+            let target = TypeId::of::<T>();
+            let mut send = |kind| {
+                events.send(AnimatorEvent {
+                    entity,
+                    target,
+                    kind,
+                })
+            };
+            if self.tweenable_replaced {
+                self.tweenable_replaced = false;
+                send(AnimatorEventKind::TweenableReplaced);
+            }
+            if self.state != self.last_state {
+                self.last_state = self.state;
+                send(match self.state {
+                    AnimatorState::Playing => AnimatorEventKind::Resumed,
+                    AnimatorState::Paused => AnimatorEventKind::Paused,
+                });
+            }
+        }
+
+        /// Tick the animator by the given time delta, scaled by its speed.
+        ///
+        /// This ticks the outgoing tweenable being blended out if any, and starts
+        /// the queued tweenables as the current one completes.
+        fn tick(
+            &mut self,
+            delta: Duration,
+            target: &mut dyn Targetable<T>,
+            entity: Entity,
+            events: &mut TweenEvents<'_>,
+            animator_events: &mut Events<AnimatorEvent>,
+        ) -> TweenState {
+            let speed = self.speed;
+            let mut delta = delta.mul_f32(speed.abs());
+
+            // Tick the outgoing tweenable on a copy of the target, to blend it
+            // below with the value produced by the current tweenable
+            let blend_out = self.blend_out.take().map(|mut blend_out| {
+                let mut value = (blend_out.clone)(target.target_mut());
+                let mut value_target = ValueTarget(&mut value);
+                if speed < 0. {
+                    blend_out
+                        .tweenable
+                        .tick_backward(delta, &mut value_target, entity, events);
+                } else {
+                    blend_out
+                        .tweenable
+                        .tick(delta, &mut value_target, entity, events);
+                }
+                blend_out.elapsed += delta;
+                (blend_out, value)
+            });
+
+            let mut elapsed = self.tweenable.elapsed();
+            let mut state = plugin::tick_tweenable(
+                self.tweenable.as_mut(),
+                speed,
+                delta,
+                target,
+                entity,
+                events,
+                animator_events,
+            );
+            while state == TweenState::Completed && speed >= 0. {
+                let Some(next) = self.queue.pop_front() else {
+                    break;
+                };
+                // Carry over the time left after the completed tweenable, so the
+                // next one starts without any hitch
+                if let TotalDuration::Finite(total_duration) = self.tweenable.total_duration() {
+                    delta = delta.saturating_sub(total_duration.saturating_sub(elapsed));
+                }
+                self.tweenable = next;
+                elapsed = self.tweenable.elapsed();
+                animator_events.send(AnimatorEvent {
+                    entity,
+                    target: TypeId::of::<T>(),
+                    kind: AnimatorEventKind::TweenableReplaced,
+                });
+                state = plugin::tick_tweenable(
+                    self.tweenable.as_mut(),
+                    speed,
+                    delta,
+                    target,
+                    entity,
+                    events,
+                    animator_events,
+                );
+            }
+
+            if let Some((blend_out, value)) = blend_out {
+                let ratio =
+                    (blend_out.elapsed.as_secs_f32() / blend_out.duration.as_secs_f32()).min(1.);
+                // Blend the target back toward the outgoing value, so the fields
+                // the blend doesn't cover keep the value of the current tweenable
+                (blend_out.blend)(target.target_mut(), &value, 1. - ratio);
+                if ratio < 1. {
+                    self.blend_out = Some(blend_out);
+                }
+            }
+            state
+        }
+
         /// Is the animation currently playing?
         ///
         /// This is a convenient function for checking if the animator state is equal to [`AnimatorState::Playing`].
@@ -437,7 +937,14 @@ macro_rules! animator_impl {
 pub struct Animator<T: Component> {
     /// Control if this animation is played or not.
     pub state: AnimatorState,
+    last_state: AnimatorState,
     tweenable: BoxedTweenable<T>,
+    tweenable_replaced: bool,
+    queue: VecDeque<BoxedTweenable<T>>,
+    blend_out: Option<BlendOut<T>>,
+    completion_policy: CompletionPolicy,
+    group: Option<String>,
+    time_source: Option<TimeSource>,
     speed: f32,
 }
 
@@ -455,7 +962,14 @@ impl<T: Component> Animator<T> {
     pub fn new(tween: impl Tweenable<T> + 'static) -> Self {
         Self {
             state: default(),
+            last_state: default(),
             tweenable: Box::new(tween),
+            tweenable_replaced: false,
+            queue: default(),
+            blend_out: None,
+            completion_policy: default(),
+            group: None,
+            time_source: None,
             speed: 1.,
         }
     }
@@ -469,7 +983,14 @@ impl<T: Component> Animator<T> {
 pub struct AssetAnimator<T: Asset> {
     /// Control if this animation is played or not.
     pub state: AnimatorState,
+    last_state: AnimatorState,
     tweenable: BoxedTweenable<T>,
+    tweenable_replaced: bool,
+    queue: VecDeque<BoxedTweenable<T>>,
+    blend_out: Option<BlendOut<T>>,
+    completion_policy: CompletionPolicy,
+    group: Option<String>,
+    time_source: Option<TimeSource>,
     handle: Handle<T>,
     speed: f32,
 }
@@ -490,7 +1011,14 @@ impl<T: Asset> AssetAnimator<T> {
     pub fn new(handle: Handle<T>, tween: impl Tweenable<T> + 'static) -> Self {
         Self {
             state: default(),
+            last_state: default(),
             tweenable: Box::new(tween),
+            tweenable_replaced: false,
+            queue: default(),
+            blend_out: None,
+            completion_policy: default(),
+            group: None,
+            time_source: None,
             handle,
             speed: 1.,
         }
@@ -729,6 +1257,96 @@ mod tests {
         assert_eq!(animator.tweenable().duration(), Duration::from_secs(2));
     }
 
+    #[test]
+    fn animator_reverse() {
+        // Apply the current state of the animator, to check it's unchanged by
+        // reversing the animation
+        fn sample(animator: &mut Animator<DummyComponent>) -> f32 {
+            let mut component = DummyComponent::default();
+            let mut completed = Events::<TweenCompleted>::default();
+            let mut events = TweenEvents::new(&mut completed);
+            animator.tweenable_mut().tick(
+                Duration::ZERO,
+                &mut ValueTarget(&mut component),
+                Entity::from_raw(0),
+                &mut events,
+            );
+            component.value
+        }
+
+        let tween = Tween::<DummyComponent>::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        );
+        let mut animator = Animator::new(tween);
+
+        animator.tweenable_mut().set_progress(0.3);
+        assert_approx_eq!(sample(&mut animator), 0.3);
+        animator.reverse();
+        assert_eq!(
+            animator.tweenable().direction(),
+            TweeningDirection::Backward
+        );
+        assert_approx_eq!(animator.tweenable().progress(), 0.7);
+        assert_approx_eq!(sample(&mut animator), 0.3);
+
+        animator.reverse();
+        assert_eq!(animator.tweenable().direction(), TweeningDirection::Forward);
+        assert_approx_eq!(animator.tweenable().progress(), 0.3);
+        assert_approx_eq!(sample(&mut animator), 0.3);
+
+        // Reversing a completed animation plays it back from its end
+        animator.tweenable_mut().set_progress(1.);
+        assert!(animator.is_completed());
+        assert_approx_eq!(sample(&mut animator), 1.);
+        animator.reverse();
+        assert!(!animator.is_completed());
+        assert_approx_eq!(animator.tweenable().progress(), 0.);
+        assert_approx_eq!(sample(&mut animator), 1.);
+
+        // Infinite animations are mirrored inside the current iteration
+        let infinite = |strategy| {
+            let tween = Tween::<DummyComponent>::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                DummyLens { start: 0., end: 1. },
+            )
+            .with_repeat_count(RepeatCount::Infinite)
+            .with_repeat_strategy(strategy);
+            Animator::new(tween)
+        };
+        let mut animator = infinite(RepeatStrategy::Repeat);
+        animator
+            .tweenable_mut()
+            .set_elapsed(Duration::from_millis(2300));
+        assert_approx_eq!(sample(&mut animator), 0.3);
+        animator.reverse();
+        assert_eq!(animator.tweenable().elapsed(), Duration::from_millis(2700));
+        assert_eq!(
+            animator.tweenable().direction(),
+            TweeningDirection::Backward
+        );
+        assert_approx_eq!(sample(&mut animator), 0.3);
+
+        // Reversing on an iteration boundary, including before starting, keeps
+        // the current state
+        for strategy in [RepeatStrategy::Repeat, RepeatStrategy::MirroredRepeat] {
+            for elapsed in [0, 1000, 2000] {
+                let mut animator = infinite(strategy);
+                animator
+                    .tweenable_mut()
+                    .set_elapsed(Duration::from_millis(elapsed));
+                let value = sample(&mut animator);
+                animator.reverse();
+                assert_approx_eq!(sample(&mut animator), value);
+                assert!(animator.tweenable().elapsed() < Duration::from_millis(elapsed + 1000));
+                animator.reverse();
+                assert_approx_eq!(sample(&mut animator), value);
+            }
+        }
+    }
+
     #[cfg(feature = "bevy_asset")]
     #[test]
     fn asset_animator_new() {
@@ -829,6 +1447,32 @@ mod tests {
         assert_approx_eq!(animator.speed(), 3.5);
     }
 
+    #[cfg(feature = "bevy_asset")]
+    #[test]
+    fn asset_animator_reverse() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        )
+        .with_repeat_count(2)
+        .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+        let mut animator = AssetAnimator::new(Handle::<DummyAsset>::default(), tween);
+
+        // Second iteration playing backward, mirrored into the first iteration
+        // playing forward
+        animator
+            .tweenable_mut()
+            .set_elapsed(Duration::from_millis(1200));
+        assert_eq!(
+            animator.tweenable().direction(),
+            TweeningDirection::Backward
+        );
+        animator.reverse();
+        assert_eq!(animator.tweenable().elapsed(), Duration::from_millis(800));
+        assert_eq!(animator.tweenable().direction(), TweeningDirection::Forward);
+    }
+
     #[cfg(feature = "bevy_asset")]
     #[test]
     fn asset_animator_set_tweenable() {
@@ -1,10 +1,28 @@
 #[cfg(feature = "bevy_asset")]
 use bevy::asset::Asset;
-use bevy::{ecs::component::Component, prelude::*};
+#[cfg(feature = "bevy_audio")]
+use bevy::audio::AudioSink;
+use std::{any::TypeId, marker::PhantomData, time::Duration};
+
+use bevy::{
+    ecs::{
+        component::Component,
+        event::Event,
+        schedule::{IntoSystemDescriptor, StageLabelId, SystemLabelId},
+    },
+    prelude::*,
+};
 
 #[cfg(feature = "bevy_asset")]
 use crate::{tweenable::AssetTarget, AssetAnimator};
-use crate::{tweenable::ComponentTarget, Animator, AnimatorState, TweenCompleted};
+use crate::{
+    tweenable::ComponentTarget, Animator, AnimatorEvent, AnimatorEventKind, AnimatorState,
+    CompletionPolicy, LayeredAnimator, MarkerReached, Targetable, TotalDuration, TweenCompleted,
+    TweenEvents, TweenState, Tweenable, TweeningClock, TweeningTime,
+};
+
+/// Function adding to an app the systems animating some type.
+type AddSystems = fn(&mut App, &TweeningPluginBuilder);
 
 /// Plugin to add systems related to tweening of common components and assets.
 ///
@@ -14,7 +32,14 @@ use crate::{tweenable::ComponentTarget, Animator, AnimatorState, TweenCompleted}
 /// - [`Text`]
 /// - [`Style`]
 /// - [`Sprite`]
+/// - [`TextureAtlasSprite`]
 /// - [`ColorMaterial`]
+/// - [`StandardMaterial`] (with the `bevy_pbr` feature)
+/// - [`PointLight`], [`SpotLight`], and [`DirectionalLight`] (with the
+///   `bevy_pbr` feature)
+/// - [`OrthographicProjection`], [`PerspectiveProjection`], [`Projection`], and
+///   [`Visibility`] (with the `bevy_render` feature)
+/// - [`AudioSink`] (with the `bevy_audio` feature)
 ///
 /// This ensures that all predefined lenses work as intended, as well as any
 /// custom lens animating the same component or asset type.
@@ -23,71 +48,542 @@ use crate::{tweenable::ComponentTarget, Animator, AnimatorState, TweenCompleted}
 /// needs to be added manually by the application:
 /// - For components, add [`component_animator_system::<T>`] where `T:
 ///   Component`
+/// - For components animated by a [`LayeredAnimator`], including the
+///   predefined ones above, add [`layered_animator_system::<T>`] where `T:
+///   Component`
 /// - For assets, add [`asset_animator_system::<T>`] where `T: Asset`
 ///
+/// Alternatively, use a [`TweeningPluginBuilder`] from [`builder()`] to add
+/// those types to the predefined set, or from [`empty()`] to only add the
+/// systems for the types actually animated. The builder also allows placing the
+/// systems in another stage than [`CoreStage::Update`], and ordering them
+/// relative to other systems.
+///
+/// This plugin also registers the [`TweenCompleted`], [`MarkerReached`], and
+/// [`AnimatorEvent`] events, and adds the [`TweeningTime`] resource updated by
+/// the [`tweening_time_system`].
+///
 /// This plugin is entirely optional. If you want more control, you can instead
 /// add manually the relevant systems for the exact set of components and assets
-/// actually animated.
+/// actually animated, as well as the events and resource above.
 ///
+/// [`builder()`]: TweeningPlugin::builder
+/// [`empty()`]: TweeningPlugin::empty
 /// [`Transform`]: https://docs.rs/bevy/0.9.0/bevy/transform/components/struct.Transform.html
 /// [`Text`]: https://docs.rs/bevy/0.9.0/bevy/text/struct.Text.html
 /// [`Style`]: https://docs.rs/bevy/0.9.0/bevy/ui/struct.Style.html
 /// [`Sprite`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.Sprite.html
+/// [`TextureAtlasSprite`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.TextureAtlasSprite.html
 /// [`ColorMaterial`]: https://docs.rs/bevy/0.9.0/bevy/sprite/struct.ColorMaterial.html
+/// [`StandardMaterial`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.StandardMaterial.html
+/// [`PointLight`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.PointLight.html
+/// [`SpotLight`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.SpotLight.html
+/// [`DirectionalLight`]: https://docs.rs/bevy/0.9.0/bevy/pbr/struct.DirectionalLight.html
+/// [`OrthographicProjection`]: https://docs.rs/bevy/0.9.0/bevy/render/camera/struct.OrthographicProjection.html
+/// [`PerspectiveProjection`]: https://docs.rs/bevy/0.9.0/bevy/render/camera/struct.PerspectiveProjection.html
+/// [`Projection`]: https://docs.rs/bevy/0.9.0/bevy/render/camera/enum.Projection.html
+/// [`Visibility`]: https://docs.rs/bevy/0.9.0/bevy/render/view/struct.Visibility.html
+/// [`AudioSink`]: https://docs.rs/bevy/0.9.0/bevy/audio/struct.AudioSink.html
+/// [`asset_animator_system::<T>`]: crate::asset_animator_system
 #[derive(Debug, Clone, Copy)]
 pub struct TweeningPlugin;
 
+impl TweeningPlugin {
+    /// Create a plugin builder not adding the systems of any component or
+    /// asset.
+    ///
+    /// See [`TweeningPluginBuilder::empty()`].
+    #[must_use]
+    pub fn empty() -> TweeningPluginBuilder {
+        TweeningPluginBuilder::empty()
+    }
+
+    /// Create a plugin builder adding the systems of the same predefined set of
+    /// components and assets as the [`TweeningPlugin`] itself, to extend it with
+    /// custom types or configure the placement of the systems.
+    #[must_use]
+    pub fn builder() -> TweeningPluginBuilder {
+        TweeningPluginBuilder::default()
+    }
+}
+
 impl Plugin for TweeningPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<TweenCompleted>().add_system(
-            component_animator_system::<Transform>.label(AnimationSystem::AnimationUpdate),
-        );
+        Self::builder().build(app);
+    }
+}
+
+/// Configurable version of the [`TweeningPlugin`], selecting the animated
+/// types and the placement of the systems.
+///
+/// This is itself a plugin, to add directly to the app in place of the
+/// [`TweeningPlugin`]:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// # #[derive(SystemLabel)]
+/// # struct PhysicsSystem;
+/// # #[derive(Component)]
+/// # struct Health(f32);
+/// App::default().add_plugin(
+///     TweeningPlugin::empty()
+///         .with_component::<Transform>()
+///         .with_component::<Health>()
+///         .with_stage(CoreStage::PostUpdate)
+///         .after(PhysicsSystem),
+/// );
+/// ```
+///
+/// All the animator systems are labelled with
+/// [`AnimationSystem::AnimationUpdate`]:
+/// - [`with_component::<T>()`] adds [`component_animator_system::<T>`] for
+///   the [`Animator<T>`]
+/// - [`with_layered_component::<T>()`] adds [`layered_animator_system::<T>`]
+///   for the [`LayeredAnimator<T>`]
+/// - [`with_asset::<T>()`] adds [`asset_animator_system::<T>`] for the
+///   [`AssetAnimator<T>`]
+///
+/// Like the [`TweeningPlugin`], this also registers the events, the
+/// [`TweeningTime`] resource and the [`tweening_time_system`], whatever the
+/// selected types.
+///
+/// [`with_component::<T>()`]: TweeningPluginBuilder::with_component
+/// [`with_layered_component::<T>()`]: TweeningPluginBuilder::with_layered_component
+/// [`with_asset::<T>()`]: TweeningPluginBuilder::with_asset
+/// [`AssetAnimator<T>`]: crate::AssetAnimator
+/// [`asset_animator_system::<T>`]: crate::asset_animator_system
+#[derive(Debug, Clone)]
+pub struct TweeningPluginBuilder {
+    /// Functions adding the systems for each animated type and clock, keyed by
+    /// the type of animator or clock to avoid adding them twice.
+    systems: Vec<(TypeId, AddSystems)>,
+    stage: StageLabelId,
+    before: Vec<SystemLabelId>,
+    after: Vec<SystemLabelId>,
+}
+
+impl Default for TweeningPluginBuilder {
+    fn default() -> Self {
+        let mut plugin = Self::empty();
+
+        plugin.add_component::<Transform>();
 
         #[cfg(feature = "bevy_ui")]
-        app.add_system(component_animator_system::<Style>.label(AnimationSystem::AnimationUpdate));
+        plugin.add_component::<Style>();
 
         #[cfg(feature = "bevy_sprite")]
-        app.add_system(component_animator_system::<Sprite>.label(AnimationSystem::AnimationUpdate));
+        {
+            plugin.add_component::<Sprite>();
+            plugin.add_component::<TextureAtlasSprite>();
+        }
 
         #[cfg(all(feature = "bevy_sprite", feature = "bevy_asset"))]
-        app.add_system(
-            asset_animator_system::<ColorMaterial>.label(AnimationSystem::AnimationUpdate),
-        );
+        plugin.add_asset::<ColorMaterial>();
+
+        #[cfg(all(feature = "bevy_pbr", feature = "bevy_asset"))]
+        plugin.add_asset::<StandardMaterial>();
 
         #[cfg(feature = "bevy_text")]
-        app.add_system(component_animator_system::<Text>.label(AnimationSystem::AnimationUpdate));
+        plugin.add_component::<Text>();
+
+        #[cfg(feature = "bevy_pbr")]
+        {
+            plugin.add_component::<PointLight>();
+            plugin.add_component::<SpotLight>();
+            plugin.add_component::<DirectionalLight>();
+        }
+
+        #[cfg(feature = "bevy_render")]
+        {
+            plugin.add_component::<OrthographicProjection>();
+            plugin.add_component::<PerspectiveProjection>();
+            plugin.add_component::<Projection>();
+            plugin.add_component::<Visibility>();
+        }
+
+        #[cfg(feature = "bevy_audio")]
+        plugin.add_asset::<AudioSink>();
+
+        plugin
+    }
+}
+
+impl TweeningPluginBuilder {
+    /// Create a plugin builder not adding the systems of any component or
+    /// asset.
+    ///
+    /// The events, resource and time system are still added.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            systems: vec![],
+            stage: CoreStage::Update.as_label(),
+            before: vec![],
+            after: vec![],
+        }
+    }
+
+    /// Add the system animating the component `T` with an [`Animator`].
+    #[must_use]
+    pub fn with_component<T: Component>(mut self) -> Self {
+        self.add_component::<T>();
+        self
+    }
+
+    /// Add the system animating the component `T` with an [`Animator`].
+    pub fn add_component<T: Component>(&mut self) {
+        self.add_systems(TypeId::of::<Animator<T>>(), add_component_systems::<T>);
+    }
+
+    /// Add the system animating the component `T` with a [`LayeredAnimator`].
+    ///
+    /// The predefined set of types doesn't include any layered animator
+    /// system, so this is needed to use a [`LayeredAnimator`] for any type.
+    #[must_use]
+    pub fn with_layered_component<T: Component>(mut self) -> Self {
+        self.add_layered_component::<T>();
+        self
+    }
+
+    /// Add the system animating the component `T` with a [`LayeredAnimator`].
+    ///
+    /// The predefined set of types doesn't include any layered animator
+    /// system, so this is needed to use a [`LayeredAnimator`] for any type.
+    pub fn add_layered_component<T: Component>(&mut self) {
+        self.add_systems(
+            TypeId::of::<LayeredAnimator<T>>(),
+            add_layered_component_systems::<T>,
+        );
+    }
+
+    /// Add the system animating the asset `T` with an [`AssetAnimator`].
+    ///
+    /// This requires the `bevy_asset` feature (enabled by default).
+    #[cfg(feature = "bevy_asset")]
+    #[must_use]
+    pub fn with_asset<T: Asset>(mut self) -> Self {
+        self.add_asset::<T>();
+        self
+    }
+
+    /// Add the system animating the asset `T` with an [`AssetAnimator`].
+    ///
+    /// This requires the `bevy_asset` feature (enabled by default).
+    #[cfg(feature = "bevy_asset")]
+    pub fn add_asset<T: Asset>(&mut self) {
+        self.add_systems(TypeId::of::<AssetAnimator<T>>(), add_asset_systems::<T>);
+    }
+
+    /// Add the [`clock_time_system::<C>`] driving the animators using
+    /// [`TimeSource::Clock`] with the clock `C`.
+    ///
+    /// Without a clock system, that time source doesn't advance.
+    ///
+    /// [`TimeSource::Clock`]: crate::TimeSource::Clock
+    #[must_use]
+    pub fn with_clock<C: TweeningClock>(mut self) -> Self {
+        self.add_clock::<C>();
+        self
+    }
+
+    /// Add the [`clock_time_system::<C>`] driving the animators using
+    /// [`TimeSource::Clock`] with the clock `C`.
+    ///
+    /// Without a clock system, that time source doesn't advance.
+    ///
+    /// [`TimeSource::Clock`]: crate::TimeSource::Clock
+    pub fn add_clock<C: TweeningClock>(&mut self) {
+        self.add_systems(TypeId::of::<C>(), add_clock_systems::<C>);
+    }
+
+    /// Set the stage the systems are added to. Defaults to
+    /// [`CoreStage::Update`].
+    #[must_use]
+    pub fn with_stage(mut self, stage: impl StageLabel) -> Self {
+        self.stage = stage.as_label();
+        self
+    }
+
+    /// Run the animator systems before the systems with the given label.
+    #[must_use]
+    pub fn before(mut self, label: impl SystemLabel) -> Self {
+        self.before.push(label.as_label());
+        self
+    }
+
+    /// Run the animator systems after the systems with the given label.
+    #[must_use]
+    pub fn after(mut self, label: impl SystemLabel) -> Self {
+        self.after.push(label.as_label());
+        self
+    }
+
+    /// Add the function adding the systems of an animator type, unless
+    /// already added.
+    fn add_systems(&mut self, animator: TypeId, add: AddSystems) {
+        if !self.has_systems(animator) {
+            self.systems.push((animator, add));
+        }
+    }
+
+    /// Check if the systems of an animator type were added.
+    fn has_systems(&self, animator: TypeId) -> bool {
+        self.systems.iter().any(|(id, _)| *id == animator)
+    }
+
+    /// Add an animator system, in the configured stage and order.
+    fn add_animator_system<Params>(
+        &self,
+        app: &mut App,
+        system: impl IntoSystemDescriptor<Params>,
+    ) {
+        let mut system = system.label(AnimationSystem::AnimationUpdate);
+        for label in &self.before {
+            system = system.before(*label);
+        }
+        for label in &self.after {
+            system = system.after(*label);
+        }
+        app.add_system_to_stage(self.stage, system);
+    }
+}
+
+impl Plugin for TweeningPluginBuilder {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TweenCompleted>()
+            .add_event::<MarkerReached>()
+            .add_event::<AnimatorEvent>()
+            .init_resource::<TweeningTime>()
+            .add_system_to_stage(
+                self.stage,
+                tweening_time_system
+                    .label(AnimationSystem::TimeUpdate)
+                    .before(AnimationSystem::AnimationUpdate),
+            );
+
+        for (_, add) in &self.systems {
+            add(app, self);
+        }
+    }
+}
+
+/// Add the system animating the component `T` with an [`Animator`].
+fn add_component_systems<T: Component>(app: &mut App, plugin: &TweeningPluginBuilder) {
+    plugin.add_animator_system(app, component_animator_system::<T>);
+}
+
+/// Add the system animating the component `T` with a [`LayeredAnimator`].
+///
+/// Both animator systems of a component write to it, so the layers are applied
+/// last, on top of the value written by the [`Animator`] if any.
+fn add_layered_component_systems<T: Component>(app: &mut App, plugin: &TweeningPluginBuilder) {
+    if plugin.has_systems(TypeId::of::<Animator<T>>()) {
+        plugin.add_animator_system(
+            app,
+            layered_animator_system::<T>.after(component_animator_system::<T>),
+        );
+    } else {
+        plugin.add_animator_system(app, layered_animator_system::<T>);
+    }
+}
+
+/// Add the time system copying the time delta of the clock `C`.
+fn add_clock_systems<C: TweeningClock>(app: &mut App, plugin: &TweeningPluginBuilder) {
+    app.add_system_to_stage(
+        plugin.stage,
+        clock_time_system::<C>
+            .label(AnimationSystem::TimeUpdate)
+            .before(AnimationSystem::AnimationUpdate),
+    );
+}
+
+/// Add the system animating the asset `T` with an [`AssetAnimator`].
+#[cfg(feature = "bevy_asset")]
+fn add_asset_systems<T: Asset>(app: &mut App, plugin: &TweeningPluginBuilder) {
+    plugin.add_animator_system(app, asset_animator_system::<T>);
+}
+
+/// Plugin registering an event type raised by tweenables, in addition to
+/// [`TweenCompleted`].
+///
+/// Add one such plugin for each event type used with
+/// [`Tween::with_completed_payload()`] and similar, so that the event can be
+/// sent and read.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// #[derive(Debug, Clone)]
+/// enum DoorEvent {
+///     Opened,
+///     Closed,
+/// }
+///
+/// App::default()
+///     .add_plugin(TweeningPlugin)
+///     .add_plugin(TweenEventPlugin::<DoorEvent>::default());
+/// ```
+///
+/// [`Tween::with_completed_payload()`]: crate::Tween::with_completed_payload
+#[derive(Debug, Clone, Copy)]
+pub struct TweenEventPlugin<E: Event>(PhantomData<E>);
+
+impl<E: Event> Default for TweenEventPlugin<E> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<E: Event> Plugin for TweenEventPlugin<E> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<E>();
     }
 }
 
 /// Label enum for the systems relating to animations
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, SystemLabel)]
 pub enum AnimationSystem {
+    /// Updates the time sources of the [`TweeningTime`]
+    TimeUpdate,
     /// Ticks animations
     AnimationUpdate,
 }
 
+/// Time system updating the [`TweeningTime`].
+///
+/// This system advances the [`TimeSource::Fixed`] time source by the virtual
+/// time delta of the frame, or by the steps reported with
+/// [`TweeningTime::advance_fixed()`]. It needs to run before the animator
+/// systems.
+///
+/// [`TimeSource::Fixed`]: crate::TimeSource::Fixed
+pub fn tweening_time_system(time: Res<Time>, mut tweening_time: ResMut<TweeningTime>) {
+    tweening_time.update_fixed(time.delta());
+}
+
+/// Time system advancing the [`TimeSource::Fixed`] time source by one
+/// [`TweeningTime::fixed_step()`] each time it runs.
+///
+/// Add this system to the fixed timestep stage of the application, so that the
+/// animators using [`TimeSource::Fixed`] advance in phase with that stage. See
+/// [`TweeningTime::advance_fixed()`].
+///
+/// ```
+/// # use bevy::{prelude::*, time::FixedTimestep};
+/// # use bevy_tweening::*;
+/// # use std::time::Duration;
+/// App::default()
+///     .add_plugin(TweeningPlugin)
+///     .insert_resource(TweeningTime::default().with_fixed_step(Duration::from_millis(20)))
+///     .add_stage_before(
+///         CoreStage::Update,
+///         "fixed_update",
+///         SystemStage::parallel()
+///             .with_run_criteria(FixedTimestep::step(0.02))
+///             .with_system(fixed_time_system),
+///     );
+/// ```
+///
+/// [`TimeSource::Fixed`]: crate::TimeSource::Fixed
+pub fn fixed_time_system(mut tweening_time: ResMut<TweeningTime>) {
+    let step = tweening_time.fixed_step();
+    tweening_time.advance_fixed(step);
+}
+
+/// Time system copying the time delta of a [`TweeningClock`] resource into the
+/// [`TweeningTime`], to drive the animators using [`TimeSource::Clock`].
+///
+/// This system needs to run before the animator systems.
+///
+/// [`TimeSource::Clock`]: crate::TimeSource::Clock
+pub fn clock_time_system<C: TweeningClock>(clock: Res<C>, mut tweening_time: ResMut<TweeningTime>) {
+    tweening_time.set_clock_delta(clock.delta());
+}
+
 /// Animator system for components.
 ///
 /// This system extracts all components of type `T` with an `Animator<T>`
 /// attached to the same entity, and tick the animator to animate the component.
 pub fn component_animator_system<T: Component>(
     time: Res<Time>,
+    tweening_time: Res<TweeningTime>,
     mut query: Query<(Entity, &mut T, &mut Animator<T>)>,
-    events: ResMut<Events<TweenCompleted>>,
+    mut events: ResMut<Events<TweenCompleted>>,
+    mut animator_events: ResMut<Events<AnimatorEvent>>,
+    mut commands: Commands,
 ) {
-    let mut events: Mut<Events<TweenCompleted>> = events.into();
+    let mut events = TweenEvents::new(&mut events);
+    let mut completed = vec![];
     for (entity, target, mut animator) in query.iter_mut() {
-        if animator.state != AnimatorState::Paused {
-            let speed = animator.speed();
+        if animator.has_changes() {
+            animator.notify_changes(entity, &mut animator_events);
+        }
+        if animator.state == AnimatorState::Paused {
+            continue;
+        }
+        if let Some(scale) = tweening_time.effective_scale(animator.group()) {
+            let delta = tweening_time.delta(animator.time_source(), &time);
             let mut target = ComponentTarget::new(target);
-            animator.tweenable_mut().tick(
-                time.delta().mul_f32(speed),
+            let state = animator.tick(
+                delta.mul_f32(scale),
                 &mut target,
                 entity,
                 &mut events,
+                &mut animator_events,
             );
+            // Rewinding to the start with a negative speed isn't a completion
+            if state == TweenState::Completed && animator.speed() >= 0. {
+                completed.push((entity, animator.completion_policy()));
+            }
         }
     }
+    for op in events.take_deferred() {
+        commands.add(op);
+    }
+    // Apply completion policies last, so deferred callbacks can still access
+    // the entity
+    for (entity, policy) in completed {
+        apply_completion_policy::<Animator<T>>(&mut commands, entity, policy);
+    }
+}
+
+/// Layered animator system for components.
+///
+/// This system extracts all components of type `T` with a
+/// [`LayeredAnimator<T>`] attached to the same entity, and ticks all the layers
+/// of the animator to animate the component.
+///
+/// When an entity has both an [`Animator<T>`] and a [`LayeredAnimator<T>`],
+/// this system needs to run after [`component_animator_system::<T>`], for the
+/// layers to apply on top of the value written by the animator.
+/// [`TweeningPluginBuilder`] orders both systems this way.
+pub fn layered_animator_system<T: Component>(
+    time: Res<Time>,
+    tweening_time: Res<TweeningTime>,
+    mut query: Query<(Entity, &mut T, &mut LayeredAnimator<T>)>,
+    mut events: ResMut<Events<TweenCompleted>>,
+    mut commands: Commands,
+) {
+    let mut events = TweenEvents::new(&mut events);
+    for (entity, target, mut animator) in query.iter_mut() {
+        let Some(scale) = tweening_time.effective_scale(animator.group()) else {
+            continue;
+        };
+        let delta = tweening_time.delta(animator.time_source(), &time);
+        let mut target = ComponentTarget::new(target);
+        animator.tick_layers(&mut target, |tweenable, speed, target| {
+            let delta = delta.mul_f32(scale * speed.abs());
+            if speed < 0. {
+                tweenable.tick_backward(delta, target, entity, &mut events);
+            } else {
+                tweenable.tick(delta, target, entity, &mut events);
+            }
+        });
+    }
+    for op in events.take_deferred() {
+        commands.add(op);
+    }
 }
 
 /// Animator system for assets.
@@ -99,31 +595,138 @@ pub fn component_animator_system<T: Component>(
 #[cfg(feature = "bevy_asset")]
 pub fn asset_animator_system<T: Asset>(
     time: Res<Time>,
+    tweening_time: Res<TweeningTime>,
     assets: ResMut<Assets<T>>,
     mut query: Query<(Entity, &mut AssetAnimator<T>)>,
-    events: ResMut<Events<TweenCompleted>>,
+    mut events: ResMut<Events<TweenCompleted>>,
+    mut animator_events: ResMut<Events<AnimatorEvent>>,
+    mut commands: Commands,
 ) {
-    let mut events: Mut<Events<TweenCompleted>> = events.into();
+    let mut events = TweenEvents::new(&mut events);
+    let mut completed = vec![];
     let mut target = AssetTarget::new(assets);
     for (entity, mut animator) in query.iter_mut() {
-        if animator.state != AnimatorState::Paused {
+        if animator.has_changes() {
+            animator.notify_changes(entity, &mut animator_events);
+        }
+        if animator.state == AnimatorState::Paused {
+            continue;
+        }
+        if let Some(scale) = tweening_time.effective_scale(animator.group()) {
             target.handle = animator.handle().clone();
             if !target.is_valid() {
                 continue;
             }
-            let speed = animator.speed();
-            animator.tweenable_mut().tick(
-                time.delta().mul_f32(speed),
+            let delta = tweening_time.delta(animator.time_source(), &time);
+            let state = animator.tick(
+                delta.mul_f32(scale),
                 &mut target,
                 entity,
                 &mut events,
+                &mut animator_events,
             );
+            // Rewinding to the start with a negative speed isn't a completion
+            if state == TweenState::Completed && animator.speed() >= 0. {
+                completed.push((entity, animator.completion_policy()));
+            }
         }
     }
+    for op in events.take_deferred() {
+        commands.add(op);
+    }
+    // Apply completion policies last, so deferred callbacks can still access
+    // the entity
+    for (entity, policy) in completed {
+        apply_completion_policy::<AssetAnimator<T>>(&mut commands, entity, policy);
+    }
+}
+
+/// Tick the top-level tweenable of an animator at the given signed speed, and
+/// raise the [`AnimatorEvent`] for the playback changes this produced.
+pub(crate) fn tick_tweenable<T: 'static>(
+    tweenable: &mut dyn Tweenable<T>,
+    speed: f32,
+    delta: Duration,
+    target: &mut dyn Targetable<T>,
+    entity: Entity,
+    events: &mut TweenEvents<'_>,
+    animator_events: &mut Events<AnimatorEvent>,
+) -> TweenState {
+    let iteration = |tweenable: &dyn Tweenable<T>| {
+        let times_completed = tweenable
+            .elapsed()
+            .as_nanos()
+            .checked_div(tweenable.duration().as_nanos())
+            .unwrap_or(0) as u32;
+        // Once completed, the clock stays at the end of the last iteration
+        match tweenable.total_duration() {
+            TotalDuration::Finite(total_duration) if tweenable.elapsed() >= total_duration => {
+                times_completed.saturating_sub(1)
+            }
+            _ => times_completed,
+        }
+    };
+    let elapsed = tweenable.elapsed();
+    let first_iteration = iteration(tweenable);
+    let direction = tweenable.direction();
+
+    let state = if speed < 0. {
+        tweenable.tick_backward(delta, target, entity, events)
+    } else {
+        tweenable.tick(delta, target, entity, events)
+    };
+
+    let target = TypeId::of::<T>();
+    let mut send = |kind| {
+        animator_events.send(AnimatorEvent {
+            entity,
+            target,
+            kind,
+        })
+    };
+    if speed >= 0. && elapsed.is_zero() && !tweenable.elapsed().is_zero() {
+        send(AnimatorEventKind::Started);
+    }
+    let last_iteration = iteration(tweenable);
+    if last_iteration >= first_iteration {
+        for iteration in first_iteration + 1..=last_iteration {
+            send(AnimatorEventKind::Looped { iteration });
+        }
+    } else {
+        // Playing backward moves back into the previous iterations
+        for iteration in (last_iteration..first_iteration).rev() {
+            send(AnimatorEventKind::Looped { iteration });
+        }
+    }
+    if tweenable.direction() != direction {
+        send(AnimatorEventKind::DirectionChanged {
+            direction: tweenable.direction(),
+        });
+    }
+    state
+}
+
+/// Apply the completion policy of an animator of type `A` through some
+/// [`Commands`].
+fn apply_completion_policy<A: Component>(
+    commands: &mut Commands,
+    entity: Entity,
+    policy: CompletionPolicy,
+) {
+    match policy {
+        CompletionPolicy::Keep => {}
+        CompletionPolicy::RemoveAnimator => {
+            commands.entity(entity).remove::<A>();
+        }
+        CompletionPolicy::Despawn => commands.entity(entity).despawn(),
+        CompletionPolicy::DespawnRecursive => commands.entity(entity).despawn_recursive(),
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::any::TypeId;
+
     use bevy::prelude::{Events, IntoSystem, System, Transform, World};
 
     use crate::{lens::TransformPositionLens, *};
@@ -141,6 +744,9 @@ mod tests {
         pub fn new<T: Component>(animator: T) -> Self {
             let mut world = World::new();
             world.init_resource::<Events<TweenCompleted>>();
+            world.init_resource::<Events<MarkerReached>>();
+            world.init_resource::<Events<AnimatorEvent>>();
+            world.init_resource::<TweeningTime>();
 
             let mut time = Time::default();
             time.update();
@@ -172,6 +778,7 @@ mod tests {
 
             // Tick system
             system.run((), &mut self.world);
+            system.apply_buffers(&mut self.world);
 
             // Update events after system ticked, in case system emitted some events
             let mut events = self.world.resource_mut::<Events<TweenCompleted>>();
@@ -186,8 +793,15 @@ mod tests {
                 .unwrap()
         }
 
+        /// Get the mutable animator for the transform.
+        pub fn animator_mut(&mut self) -> Mut<'_, Animator<Transform>> {
+            self.world
+                .get_mut::<Animator<Transform>>(self.entity)
+                .unwrap()
+        }
+
         /// Get the transform component.
-        pub fn transform(&mut self) -> Mut<Transform> {
+        pub fn transform(&mut self) -> Mut<'_, Transform> {
             self.world.get_mut::<Transform>(self.entity).unwrap()
         }
 
@@ -196,6 +810,20 @@ mod tests {
             let events = self.world.resource::<Events<TweenCompleted>>();
             events.get_reader().len(events)
         }
+
+        /// Drain the animator events emitted so far.
+        pub fn drain_animator_events(&mut self) -> Vec<AnimatorEventKind> {
+            let entity = self.entity;
+            let mut events = self.world.resource_mut::<Events<AnimatorEvent>>();
+            events
+                .drain()
+                .map(|event| {
+                    assert_eq!(event.entity, entity);
+                    assert_eq!(event.target, TypeId::of::<Transform>());
+                    event.kind
+                })
+                .collect()
+        }
     }
 
     #[test]
@@ -260,4 +888,851 @@ mod tests {
         assert!(!transform.is_changed());
         assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
     }
+
+    #[test]
+    fn negative_speed() {
+        let mut tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_completed_event(0);
+        tween.set_progress(1.);
+
+        let mut env = TestEnv::new(Animator::new(tween).with_speed(-2.));
+
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        env.tick(Duration::ZERO, &mut system);
+        assert!(env.transform().translation.abs_diff_eq(Vec3::ONE, 1e-5));
+
+        env.tick(Duration::from_millis(200), &mut system);
+
+        assert_eq!(env.event_count(), 0);
+        assert_eq!(env.animator().tweenable().times_completed(), 0);
+        let transform = env.transform();
+        assert!(transform.is_changed());
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.6), 1e-5));
+
+        env.tick(Duration::from_millis(400), &mut system);
+
+        // No event when reaching the start
+        assert_eq!(env.event_count(), 0);
+        let transform = env.transform();
+        assert!(transform.is_changed());
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+
+        env.tick(Duration::from_millis(100), &mut system);
+
+        assert_eq!(env.event_count(), 0);
+        let transform = env.transform();
+        assert!(!transform.is_changed());
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+    }
+
+    #[test]
+    fn animator_events() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_repeat_count(3)
+        .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+
+        let mut env = TestEnv::new(Animator::new(tween));
+
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        // Not started until time passes
+        env.tick(Duration::ZERO, &mut system);
+        assert!(env.drain_animator_events().is_empty());
+
+        env.tick(Duration::from_millis(500), &mut system);
+        assert_eq!(
+            env.drain_animator_events(),
+            vec![AnimatorEventKind::Started]
+        );
+
+        env.tick(Duration::from_millis(1000), &mut system);
+        assert_eq!(
+            env.drain_animator_events(),
+            vec![
+                AnimatorEventKind::Looped { iteration: 1 },
+                AnimatorEventKind::DirectionChanged {
+                    direction: TweeningDirection::Backward
+                }
+            ]
+        );
+
+        env.animator_mut().state = AnimatorState::Paused;
+        env.tick(Duration::from_millis(100), &mut system);
+        env.tick(Duration::from_millis(100), &mut system);
+        assert_eq!(env.drain_animator_events(), vec![AnimatorEventKind::Paused]);
+
+        // Completing the last iteration doesn't loop
+        env.animator_mut().state = AnimatorState::Playing;
+        env.tick(Duration::from_millis(2000), &mut system);
+        assert_eq!(
+            env.drain_animator_events(),
+            vec![
+                AnimatorEventKind::Resumed,
+                AnimatorEventKind::Looped { iteration: 2 },
+                AnimatorEventKind::DirectionChanged {
+                    direction: TweeningDirection::Forward
+                }
+            ]
+        );
+        env.tick(Duration::from_millis(100), &mut system);
+        assert!(env.drain_animator_events().is_empty());
+
+        env.animator_mut()
+            .set_tweenable(Delay::new(Duration::from_secs(1)));
+        env.tick(Duration::from_millis(100), &mut system);
+        assert_eq!(
+            env.drain_animator_events(),
+            vec![
+                AnimatorEventKind::TweenableReplaced,
+                AnimatorEventKind::Started
+            ]
+        );
+    }
+
+    #[test]
+    fn animator_events_backward() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_repeat_count(3)
+        .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+
+        let mut env = TestEnv::new(Animator::new(tween).with_speed(-1.));
+        env.animator_mut()
+            .tweenable_mut()
+            .set_elapsed(Duration::from_secs(3));
+
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        // Moving back into the previous iterations raises the same events as
+        // when playing forward
+        env.tick(Duration::from_millis(1500), &mut system);
+        assert_eq!(
+            env.drain_animator_events(),
+            vec![
+                AnimatorEventKind::Looped { iteration: 1 },
+                AnimatorEventKind::DirectionChanged {
+                    direction: TweeningDirection::Backward
+                }
+            ]
+        );
+
+        // Rewinding back to the start doesn't raise any Started event
+        env.tick(Duration::from_millis(1500), &mut system);
+        assert_eq!(
+            env.drain_animator_events(),
+            vec![
+                AnimatorEventKind::Looped { iteration: 0 },
+                AnimatorEventKind::DirectionChanged {
+                    direction: TweeningDirection::Forward
+                }
+            ]
+        );
+        assert_eq!(env.animator().tweenable().elapsed(), Duration::ZERO);
+        env.tick(Duration::from_millis(100), &mut system);
+        assert!(env.drain_animator_events().is_empty());
+    }
+
+    #[test]
+    fn completed_payload() {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        struct Opened(u32);
+
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_completed_payload(Opened(42));
+
+        let mut env = TestEnv::new(Animator::new(tween));
+        env.world_mut().init_resource::<Events<Opened>>();
+
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        env.tick(Duration::from_millis(500), &mut system);
+        env.tick(Duration::from_millis(500), &mut system);
+
+        let mut events = env.world_mut().resource_mut::<Events<Opened>>();
+        assert_eq!(events.drain().collect::<Vec<_>>(), vec![Opened(42)]);
+        assert_eq!(env.event_count(), 0);
+    }
+
+    #[test]
+    fn marker_reached() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_completed_event(7);
+        let marked = Marked::new(tween).with_marker(Marker::at_progress(0.5).with_event(3));
+
+        let mut env = TestEnv::new(Animator::new(marked));
+
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        // Reaching the marker doesn't raise any completion event
+        env.tick(Duration::from_millis(600), &mut system);
+        let entity = env.entity;
+        let mut markers = env.world_mut().resource_mut::<Events<MarkerReached>>();
+        let markers: Vec<_> = markers.drain().collect();
+        assert_eq!(markers.len(), 1);
+        assert_eq!(markers[0].entity, entity);
+        assert_eq!(markers[0].user_data, 3);
+        assert_eq!(env.event_count(), 0);
+
+        // Completing doesn't raise any marker event
+        env.tick(Duration::from_millis(400), &mut system);
+        let mut markers = env.world_mut().resource_mut::<Events<MarkerReached>>();
+        assert!(markers.drain().next().is_none());
+        let events = env.world_mut().resource::<Events<TweenCompleted>>();
+        let completed: Vec<_> = events
+            .get_reader()
+            .iter(events)
+            .map(|ev| ev.user_data)
+            .collect();
+        assert_eq!(completed, vec![7]);
+    }
+
+    #[test]
+    fn completed_deferred() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_completed_deferred(|entity, world| {
+            world.despawn(entity);
+        });
+
+        let mut env = TestEnv::new(Animator::new(tween));
+
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        env.tick(Duration::from_millis(500), &mut system);
+        let entity = env.entity;
+        assert!(env.world_mut().get_entity(entity).is_some());
+
+        env.tick(Duration::from_millis(500), &mut system);
+        assert!(env.world_mut().get_entity(entity).is_none());
+    }
+
+    #[test]
+    fn completion_policy() {
+        for policy in [
+            CompletionPolicy::Keep,
+            CompletionPolicy::RemoveAnimator,
+            CompletionPolicy::Despawn,
+            CompletionPolicy::DespawnRecursive,
+        ] {
+            let tween = Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            );
+            let animator = Animator::new(tween).with_completion_policy(policy);
+            assert_eq!(animator.completion_policy(), policy);
+
+            let mut env = TestEnv::new(animator);
+            let entity = env.entity;
+            let child = env.world_mut().spawn_empty().id();
+            env.world_mut().entity_mut(entity).push_children(&[child]);
+
+            let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+            system.initialize(env.world_mut());
+
+            env.tick(Duration::from_millis(500), &mut system);
+            assert!(env.world_mut().get_entity(entity).is_some());
+            assert!(env.world_mut().get::<Animator<Transform>>(entity).is_some());
+
+            env.tick(Duration::from_millis(500), &mut system);
+            let world = env.world_mut();
+            match policy {
+                CompletionPolicy::Keep => {
+                    assert!(world.get::<Animator<Transform>>(entity).is_some());
+                }
+                CompletionPolicy::RemoveAnimator => {
+                    assert!(world.get::<Animator<Transform>>(entity).is_none());
+                    assert!(world.get::<Transform>(entity).is_some());
+                }
+                CompletionPolicy::Despawn => {
+                    assert!(world.get_entity(entity).is_none());
+                    assert!(world.get_entity(child).is_some());
+                }
+                CompletionPolicy::DespawnRecursive => {
+                    assert!(world.get_entity(entity).is_none());
+                    assert!(world.get_entity(child).is_none());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn completion_policy_backward() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let mut animator = Animator::new(tween)
+            .with_completion_policy(CompletionPolicy::Despawn)
+            .with_speed(-1.);
+        animator.tweenable_mut().set_progress(0.5);
+
+        let mut env = TestEnv::new(animator);
+        let entity = env.entity;
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        // Rewinding back to the start doesn't apply the completion policy
+        env.tick(Duration::from_millis(500), &mut system);
+        assert_eq!(env.animator().tweenable().elapsed(), Duration::ZERO);
+        env.tick(Duration::from_millis(500), &mut system);
+        assert!(env.world_mut().get_entity(entity).is_some());
+
+        // Playing forward to the end does
+        env.animator_mut().set_speed(1.);
+        env.tick(Duration::from_secs(1), &mut system);
+        assert!(env.world_mut().get_entity(entity).is_none());
+    }
+
+    #[test]
+    fn animator_queue() {
+        let tween = |start, end| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::splat(start),
+                    end: Vec3::splat(end),
+                },
+            )
+        };
+        let mut animator = Animator::new(tween(0., 1.));
+        animator.enqueue(tween(1., 2.));
+        animator.enqueue_with(tween(2., 3.), Interruption::FinishCurrent);
+        assert_eq!(animator.queued(), 2);
+
+        let mut env = TestEnv::new(animator);
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        env.tick(Duration::from_millis(500), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(0.5), 1e-5));
+        assert_eq!(
+            env.drain_animator_events(),
+            vec![AnimatorEventKind::Started]
+        );
+
+        // The next tweenable starts with the time left over by the current one
+        env.tick(Duration::from_millis(1000), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(1.5), 1e-5));
+        assert_eq!(env.animator().queued(), 1);
+        assert_eq!(
+            env.drain_animator_events(),
+            vec![
+                AnimatorEventKind::TweenableReplaced,
+                AnimatorEventKind::Started
+            ]
+        );
+
+        // Cancel the current tweenable and the queued ones
+        env.animator_mut()
+            .enqueue_with(tween(5., 6.), Interruption::CancelCurrent);
+        assert_eq!(env.animator().queued(), 0);
+        env.tick(Duration::from_millis(500), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(5.5), 1e-5));
+
+        // Blend from the current tweenable into the next one
+        env.animator_mut()
+            .enqueue_blended(tween(10., 11.), Duration::from_secs(1));
+        assert!(env.animator().is_blending());
+        env.tick(Duration::from_millis(500), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(8.25), 1e-5));
+        assert!(env.animator().is_blending());
+        env.tick(Duration::from_millis(500), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(11.), 1e-5));
+        assert!(!env.animator().is_blending());
+        assert!(env.animator().is_completed());
+    }
+
+    #[test]
+    fn animator_crossfade() {
+        let looping = |start, end| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::splat(start),
+                    end: Vec3::splat(end),
+                },
+            )
+            .with_repeat_count(RepeatCount::Infinite)
+        };
+        let mut env = TestEnv::new(Animator::new(looping(0., 1.)));
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        env.tick(Duration::from_millis(500), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(0.5), 1e-5));
+
+        {
+            let mut animator = env.animator_mut();
+            animator.enqueue(looping(20., 21.));
+            animator.set_tweenable_blended(looping(10., 11.), Duration::from_secs(1));
+        }
+        assert!(env.animator().is_blending());
+        assert_eq!(env.animator().queued(), 1);
+        env.drain_animator_events();
+
+        // Outgoing at 0.75, incoming at 10.25, blended by 25%
+        env.tick(Duration::from_millis(250), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(3.125), 1e-4));
+        assert_eq!(
+            env.drain_animator_events(),
+            vec![
+                AnimatorEventKind::TweenableReplaced,
+                AnimatorEventKind::Started
+            ]
+        );
+
+        // Outgoing looped back to 0.25, incoming at 10.75, blended by 75%
+        env.tick(Duration::from_millis(500), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(8.125), 1e-4));
+        assert!(env.animator().is_blending());
+
+        // Blend completed, only the incoming tweenable remains
+        env.tick(Duration::from_millis(400), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(10.15), 1e-4));
+        assert!(!env.animator().is_blending());
+        assert_eq!(env.animator().queued(), 1);
+    }
+
+    #[cfg(feature = "bevy_sprite")]
+    #[test]
+    fn animator_blend_unblended_fields() {
+        use crate::lens::TextureAtlasSpriteIndexLens;
+
+        let frames = |start, end| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TextureAtlasSpriteIndexLens { start, end },
+            )
+        };
+        let mut env = TestEnv::new(Animator::new(frames(0, 3)));
+        let entity = env.entity;
+        env.world_mut()
+            .entity_mut(entity)
+            .insert(TextureAtlasSprite::default());
+        let mut system = IntoSystem::into_system(component_animator_system::<TextureAtlasSprite>);
+        system.initialize(env.world_mut());
+        let index = |env: &mut TestEnv| {
+            env.world_mut()
+                .get::<TextureAtlasSprite>(entity)
+                .unwrap()
+                .index
+        };
+
+        env.tick(Duration::from_millis(250), &mut system);
+        assert_eq!(index(&mut env), 1);
+
+        env.world_mut()
+            .get_mut::<Animator<TextureAtlasSprite>>(entity)
+            .unwrap()
+            .set_tweenable_blended(frames(10, 13), Duration::from_secs(1));
+
+        // The index isn't blended, so it's the one of the incoming tweenable
+        // during the whole blend, not the one of the outgoing tweenable
+        env.tick(Duration::from_millis(500), &mut system);
+        assert_eq!(index(&mut env), 12);
+        let sprite = env.world_mut().get::<TextureAtlasSprite>(entity).unwrap();
+        assert_eq!(sprite.color, Color::WHITE);
+
+        env.tick(Duration::from_millis(400), &mut system);
+        assert_eq!(index(&mut env), 13);
+        env.tick(Duration::from_millis(200), &mut system);
+        assert_eq!(index(&mut env), 13);
+        assert!(!env
+            .world_mut()
+            .get::<Animator<TextureAtlasSprite>>(entity)
+            .unwrap()
+            .is_blending());
+    }
+
+    #[test]
+    fn tweening_time_groups() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let animator = Animator::new(tween).with_group("gameplay");
+        assert_eq!(animator.group(), Some("gameplay"));
+        let mut env = TestEnv::new(animator);
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        env.world_mut()
+            .resource_mut::<TweeningTime>()
+            .set_group_scale("gameplay", 0.5);
+        env.tick(Duration::from_millis(200), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(0.1), 1e-5));
+
+        env.world_mut().resource_mut::<TweeningTime>().set_scale(2.);
+        env.tick(Duration::from_millis(200), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(0.3), 1e-5));
+
+        // A paused group doesn't tick, while other groups keep playing
+        env.world_mut()
+            .resource_mut::<TweeningTime>()
+            .set_group_paused("gameplay", true);
+        env.tick(Duration::from_millis(200), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(0.3), 1e-5));
+        assert!(!env.transform().is_changed());
+
+        env.animator_mut().set_group("ui");
+        env.tick(Duration::from_millis(200), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(0.7), 1e-5));
+
+        // Pausing globally freezes all groups
+        env.world_mut()
+            .resource_mut::<TweeningTime>()
+            .set_paused(true);
+        env.animator_mut().clear_group();
+        env.tick(Duration::from_millis(200), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(0.7), 1e-5));
+    }
+
+    #[test]
+    fn time_sources() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let animator = Animator::new(tween).with_time_source(TimeSource::Fixed);
+        assert_eq!(animator.time_source(), Some(TimeSource::Fixed));
+        let mut env = TestEnv::new(animator);
+        env.world_mut()
+            .resource_mut::<TweeningTime>()
+            .set_fixed_step(Duration::from_millis(100));
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        // Only whole fixed steps are applied, the rest is accumulated
+        for (delta, position) in [(150, 0.1), (150, 0.3)] {
+            let delta = Duration::from_millis(delta);
+            env.world_mut()
+                .resource_mut::<TweeningTime>()
+                .update_fixed(delta);
+            env.tick(delta, &mut system);
+            assert!(env
+                .transform()
+                .translation
+                .abs_diff_eq(Vec3::splat(position), 1e-5));
+        }
+
+        // Real time ignores the relative speed of the virtual time
+        env.world_mut()
+            .resource_mut::<Time>()
+            .set_relative_speed(0.5);
+        env.animator_mut().set_time_source(TimeSource::Real);
+        env.tick(Duration::from_millis(200), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(0.5), 1e-5));
+
+        env.animator_mut().clear_time_source();
+        env.tick(Duration::from_millis(200), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(0.6), 1e-5));
+
+        // The clock delta is set by the clock system
+        env.animator_mut().set_time_source(TimeSource::Clock);
+        env.world_mut()
+            .resource_mut::<TweeningTime>()
+            .set_clock_delta(Duration::from_millis(300));
+        env.tick(Duration::from_millis(200), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(0.9), 1e-5));
+    }
+
+    #[test]
+    fn plugin_builder() {
+        let plugin = TweeningPlugin::empty()
+            .with_component::<Transform>()
+            .with_component::<Transform>();
+        assert_eq!(plugin.systems.len(), 1);
+        let plugin = plugin.with_layered_component::<Transform>();
+        assert_eq!(plugin.systems.len(), 2);
+
+        // Layered animator systems are opt-in
+        let plugin = TweeningPlugin::builder();
+        assert!(!plugin.systems.is_empty());
+        assert!(plugin
+            .systems
+            .iter()
+            .all(|(id, _)| *id != TypeId::of::<LayeredAnimator<Transform>>()));
+
+        // The unit plugin is still usable as is
+        let mut app = App::new();
+        app.add_plugin(TweeningPlugin);
+        assert!(app.world.contains_resource::<TweeningTime>());
+        assert!(app.world.contains_resource::<Events<MarkerReached>>());
+        assert!(app.world.contains_resource::<Events<AnimatorEvent>>());
+
+        for (plugin, animated) in [
+            (TweeningPlugin::empty(), false),
+            (TweeningPlugin::empty().with_component::<Transform>(), true),
+            (
+                TweeningPlugin::empty()
+                    .with_component::<Transform>()
+                    .with_stage(CoreStage::PostUpdate)
+                    .after(AnimationSystem::TimeUpdate),
+                true,
+            ),
+        ] {
+            let mut app = App::new();
+            app.init_resource::<Time>().add_plugin(plugin);
+            let tween = Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ONE,
+                    end: Vec3::ZERO,
+                },
+            );
+            let entity = app
+                .world
+                .spawn((Transform::default(), Animator::new(tween)))
+                .id();
+            app.update();
+            let transform = app.world.get::<Transform>(entity).unwrap();
+            assert_eq!(transform.translation == Vec3::ONE, animated);
+        }
+    }
+
+    #[test]
+    fn plugin_time_sources() {
+        #[derive(Resource)]
+        struct TurnClock(Duration);
+
+        impl TweeningClock for TurnClock {
+            fn delta(&self) -> Duration {
+                self.0
+            }
+        }
+
+        let spawn = |app: &mut App, source| {
+            let tween = Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            );
+            app.world
+                .spawn((
+                    Transform::default(),
+                    Animator::new(tween).with_time_source(source),
+                ))
+                .id()
+        };
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(TurnClock(Duration::from_millis(100)))
+            .insert_resource(TweeningTime::default().with_fixed_step(Duration::from_millis(200)))
+            .add_plugin(
+                TweeningPlugin::empty()
+                    .with_component::<Transform>()
+                    .with_clock::<TurnClock>()
+                    .with_clock::<TurnClock>(),
+            )
+            .add_system_to_stage(CoreStage::PreUpdate, fixed_time_system);
+        let clock = spawn(&mut app, TimeSource::Clock);
+        let fixed = spawn(&mut app, TimeSource::Fixed);
+
+        // The frame time is zero, yet the clock and the fixed stage advance
+        for _ in 0..2 {
+            app.update();
+        }
+        let translation =
+            |app: &App, entity| app.world.get::<Transform>(entity).unwrap().translation;
+        assert!(translation(&app, clock).abs_diff_eq(Vec3::splat(0.2), 1e-5));
+        assert!(translation(&app, fixed).abs_diff_eq(Vec3::splat(0.4), 1e-5));
+    }
+
+    #[test]
+    fn layered_after_component() {
+        let tween = |end| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens { start: end, end },
+            )
+        };
+        for plugin in [
+            TweeningPlugin::empty()
+                .with_component::<Transform>()
+                .with_layered_component::<Transform>(),
+            TweeningPlugin::empty()
+                .with_layered_component::<Transform>()
+                .with_component::<Transform>(),
+        ] {
+            let mut app = App::new();
+            app.init_resource::<Time>().add_plugin(plugin);
+            let entity = app
+                .world
+                .spawn((
+                    Transform::default(),
+                    Animator::new(tween(Vec3::Y)),
+                    LayeredAnimator::new().with_layer(AnimatorLayer::new("layer", tween(Vec3::X))),
+                ))
+                .id();
+            // The layers always apply last, whatever the order in which the
+            // systems were added
+            for _ in 0..3 {
+                app.update();
+                let transform = app.world.get::<Transform>(entity).unwrap();
+                assert_eq!(transform.translation, Vec3::X);
+            }
+        }
+    }
+
+    #[test]
+    fn layered_animator() {
+        let tween = |end| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end,
+                },
+            )
+        };
+        let animator = LayeredAnimator::new()
+            .with_layer(AnimatorLayer::new(
+                "base",
+                tween(Vec3::X).with_completed_event(0),
+            ))
+            .with_layer(AnimatorLayer::additive("bob", tween(Vec3::Y)).with_speed(2.));
+
+        let mut env = TestEnv::new(animator);
+
+        let mut system = IntoSystem::into_system(layered_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        env.tick(Duration::from_millis(250), &mut system);
+        let transform = env.transform();
+        assert!(transform.is_changed());
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(0.25, 0.5, 0.), 1e-5));
+
+        env.tick(Duration::from_millis(750), &mut system);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::new(1., 1., 0.), 1e-5));
+        assert_eq!(env.event_count(), 1);
+    }
 }
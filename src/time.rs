@@ -0,0 +1,453 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, utils::HashMap};
+
+/// Source of the time delta animators advance by each frame.
+///
+/// See [`TweeningTime::set_default_source()`] and
+/// [`Animator::with_time_source()`].
+///
+/// [`Animator::with_time_source()`]: crate::Animator::with_time_source
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TimeSource {
+    /// The time delta of the [`Time`] resource, affected by its relative speed
+    /// and pause. This is the default.
+    #[default]
+    Virtual,
+    /// The unscaled real time delta of the [`Time`] resource, ignoring its
+    /// relative speed and pause.
+    Real,
+    /// The time delta of a user-provided clock resource. See [`TweeningClock`].
+    Clock,
+    /// A fixed timestep, accumulating the virtual time delta and advancing the
+    /// animations only by whole steps. See [`TweeningTime::set_fixed_step()`].
+    ///
+    /// To instead advance the animations in phase with the fixed timestep
+    /// schedule of the application, see [`TweeningTime::advance_fixed()`].
+    Fixed,
+}
+
+/// User-provided clock resource, to drive animations with
+/// [`TimeSource::Clock`].
+///
+/// The [`clock_time_system::<C>`] copies each frame the time delta of the clock
+/// into the [`TweeningTime`], before the animator systems run. Add it with
+/// [`TweeningPluginBuilder::with_clock()`]:
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// #[derive(Resource)]
+/// struct TurnClock(Duration);
+///
+/// impl TweeningClock for TurnClock {
+///     fn delta(&self) -> Duration {
+///         self.0
+///     }
+/// }
+///
+/// App::default().add_plugin(TweeningPlugin::builder().with_clock::<TurnClock>());
+/// ```
+///
+/// [`clock_time_system::<C>`]: crate::clock_time_system
+/// [`TweeningPluginBuilder::with_clock()`]: crate::TweeningPluginBuilder::with_clock
+pub trait TweeningClock: Resource {
+    /// Get the time elapsed since the previous frame.
+    fn delta(&self) -> Duration;
+}
+
+/// Playback controls of a named group of animators.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TimeGroup {
+    scale: f32,
+    paused: bool,
+}
+
+impl Default for TimeGroup {
+    fn default() -> Self {
+        Self {
+            scale: 1.,
+            paused: false,
+        }
+    }
+}
+
+/// Resource controlling the time of all animators at once.
+///
+/// The animator systems multiply the time delta of each frame by a global time
+/// scale, and by the scale of the group the animator is assigned to, if any.
+/// This allows for example slowing down all gameplay animations, or freezing
+/// them behind a pause menu while the UI animations keep running, without the
+/// need to change the speed of each individual animator.
+///
+/// Groups are identified by an arbitrary name, and don't need to be declared;
+/// a group not configured in this resource plays at normal speed. Assign an
+/// animator to a group with [`Animator::with_group()`].
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// fn pause_menu(mut tweening_time: ResMut<TweeningTime>) {
+///     // Freeze gameplay animations, but keep animating the menu
+///     tweening_time.set_group_paused("gameplay", true);
+/// }
+///
+/// fn bullet_time(mut tweening_time: ResMut<TweeningTime>) {
+///     tweening_time.set_group_scale("gameplay", 0.2);
+/// }
+/// ```
+///
+/// This resource also selects the default [`TimeSource`] of animators, and
+/// holds the time deltas of the sources not read directly from [`Time`], which
+/// are updated each frame by the [`tweening_time_system`] and the
+/// [`clock_time_system::<C>`].
+///
+/// This resource and the [`tweening_time_system`] are added by the
+/// [`TweeningPlugin`], and need to be added manually when adding the animator
+/// systems without the plugin.
+///
+/// [`Animator::with_group()`]: crate::Animator::with_group
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+/// [`tweening_time_system`]: crate::tweening_time_system
+/// [`clock_time_system::<C>`]: crate::clock_time_system
+#[derive(Debug, Clone, Resource)]
+pub struct TweeningTime {
+    global: TimeGroup,
+    groups: HashMap<String, TimeGroup>,
+    default_source: TimeSource,
+    fixed_step: Duration,
+    fixed_accumulator: Duration,
+    fixed_delta: Duration,
+    fixed_external: bool,
+    fixed_pending: Duration,
+    clock_delta: Duration,
+}
+
+impl Default for TweeningTime {
+    fn default() -> Self {
+        Self {
+            global: default(),
+            groups: default(),
+            default_source: default(),
+            fixed_step: Duration::from_nanos(1_000_000_000 / 60),
+            fixed_accumulator: Duration::ZERO,
+            fixed_delta: Duration::ZERO,
+            fixed_external: false,
+            fixed_pending: Duration::ZERO,
+            clock_delta: Duration::ZERO,
+        }
+    }
+}
+
+impl TweeningTime {
+    /// Set the global time scale, applied to all animators. Defaults to 1.
+    ///
+    /// Negative scales are clamped to zero. To play an animation backward, use
+    /// a negative animator speed instead.
+    pub fn set_scale(&mut self, scale: f32) {
+        self.global.scale = scale.max(0.);
+    }
+
+    /// Get the global time scale.
+    #[must_use]
+    pub fn scale(&self) -> f32 {
+        self.global.scale
+    }
+
+    /// Pause or resume all animators.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.global.paused = paused;
+    }
+
+    /// Are all animators paused?
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.global.paused
+    }
+
+    /// Set the time scale of a group of animators. Defaults to 1.
+    ///
+    /// The group scale is combined with the global one. Negative scales are
+    /// clamped to zero.
+    pub fn set_group_scale(&mut self, group: impl Into<String>, scale: f32) {
+        self.groups.entry(group.into()).or_default().scale = scale.max(0.);
+    }
+
+    /// Get the time scale of a group of animators.
+    #[must_use]
+    pub fn group_scale(&self, group: &str) -> f32 {
+        self.groups.get(group).map_or(1., |group| group.scale)
+    }
+
+    /// Pause or resume a group of animators.
+    pub fn set_group_paused(&mut self, group: impl Into<String>, paused: bool) {
+        self.groups.entry(group.into()).or_default().paused = paused;
+    }
+
+    /// Is a group of animators paused?
+    ///
+    /// This only reports the pause flag of the group itself; see
+    /// [`is_paused()`] for the global one.
+    ///
+    /// [`is_paused()`]: TweeningTime::is_paused
+    #[must_use]
+    pub fn is_group_paused(&self, group: &str) -> bool {
+        self.groups.get(group).is_some_and(|group| group.paused)
+    }
+
+    /// Reset the global and all group controls to their default.
+    ///
+    /// This doesn't affect the time sources.
+    pub fn reset(&mut self) {
+        self.global = default();
+        self.groups.clear();
+    }
+
+    /// Set the time source of the animators not selecting their own.
+    #[must_use]
+    pub fn with_default_source(mut self, source: TimeSource) -> Self {
+        self.default_source = source;
+        self
+    }
+
+    /// Set the time source of the animators not selecting their own. Defaults
+    /// to [`TimeSource::Virtual`].
+    pub fn set_default_source(&mut self, source: TimeSource) {
+        self.default_source = source;
+    }
+
+    /// Get the time source of the animators not selecting their own.
+    #[must_use]
+    pub fn default_source(&self) -> TimeSource {
+        self.default_source
+    }
+
+    /// Set the step of the [`TimeSource::Fixed`] time source.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step is zero.
+    #[must_use]
+    pub fn with_fixed_step(mut self, step: Duration) -> Self {
+        self.set_fixed_step(step);
+        self
+    }
+
+    /// Set the step of the [`TimeSource::Fixed`] time source. Defaults to 1/60
+    /// second.
+    ///
+    /// To keep animations aligned with a fixed timestep simulation, use the same
+    /// step as that simulation. The time left over after the last whole step of
+    /// a frame is accumulated in integral nanoseconds, so the steps taken only
+    /// depend on the sequence of frame time deltas.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step is zero.
+    pub fn set_fixed_step(&mut self, step: Duration) {
+        assert!(!step.is_zero(), "Fixed timestep cannot be zero.");
+        self.fixed_step = step;
+    }
+
+    /// Get the step of the [`TimeSource::Fixed`] time source.
+    #[must_use]
+    pub fn fixed_step(&self) -> Duration {
+        self.fixed_step
+    }
+
+    /// Get the time accumulated by the [`TimeSource::Fixed`] time source, not
+    /// yet consumed by a whole step.
+    ///
+    /// This stays zero once the fixed time source is advanced with
+    /// [`advance_fixed()`].
+    ///
+    /// [`advance_fixed()`]: TweeningTime::advance_fixed
+    #[must_use]
+    pub fn fixed_accumulator(&self) -> Duration {
+        self.fixed_accumulator
+    }
+
+    /// Advance the [`TimeSource::Fixed`] time source by a step of the fixed
+    /// timestep schedule of the application.
+    ///
+    /// By default the fixed time source accumulates the virtual time delta of
+    /// each frame on its own, so its steps can drift out of phase with a fixed
+    /// timestep simulation. Call this method from that simulation instead, for
+    /// example with the [`fixed_time_system`] added to its stage. Once called,
+    /// the fixed time source only advances by the steps reported this way.
+    ///
+    /// The steps reported during a frame are applied by the animator systems of
+    /// that frame, so the fixed timestep schedule needs to run before them.
+    ///
+    /// [`fixed_time_system`]: crate::fixed_time_system
+    pub fn advance_fixed(&mut self, step: Duration) {
+        self.fixed_external = true;
+        self.fixed_pending += step;
+    }
+
+    /// Get the time delta of this frame for the given time source, or the
+    /// default one if `None`.
+    #[must_use]
+    pub fn delta(&self, source: Option<TimeSource>, time: &Time) -> Duration {
+        match source.unwrap_or(self.default_source) {
+            TimeSource::Virtual => time.delta(),
+            TimeSource::Real => time.raw_delta(),
+            TimeSource::Clock => self.clock_delta,
+            TimeSource::Fixed => self.fixed_delta,
+        }
+    }
+
+    /// Accumulate the virtual time delta of this frame, and advance the fixed
+    /// timestep by as many whole steps as possible, unless the steps are
+    /// reported with [`advance_fixed()`].
+    ///
+    /// [`advance_fixed()`]: TweeningTime::advance_fixed
+    pub(crate) fn update_fixed(&mut self, delta: Duration) {
+        if self.fixed_external {
+            self.fixed_delta = std::mem::take(&mut self.fixed_pending);
+            return;
+        }
+        self.fixed_accumulator += delta;
+        let steps = self.fixed_accumulator.as_nanos() / self.fixed_step.as_nanos();
+        self.fixed_delta = Duration::from_nanos((self.fixed_step.as_nanos() * steps) as u64);
+        self.fixed_accumulator -= self.fixed_delta;
+    }
+
+    /// Set the time delta of this frame for the [`TimeSource::Clock`] time
+    /// source.
+    pub(crate) fn set_clock_delta(&mut self, delta: Duration) {
+        self.clock_delta = delta;
+    }
+
+    /// Get the effective time scale of an animator assigned to the given group,
+    /// if any.
+    ///
+    /// This combines the global and group scales, and returns `None` if either
+    /// is paused.
+    #[must_use]
+    pub fn effective_scale(&self, group: Option<&str>) -> Option<f32> {
+        let group = group
+            .and_then(|group| self.groups.get(group))
+            .copied()
+            .unwrap_or_default();
+        if self.global.paused || group.paused {
+            None
+        } else {
+            Some(self.global.scale * group.scale)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tweening_time() {
+        let mut time = TweeningTime::default();
+        assert_eq!(time.scale(), 1.);
+        assert!(!time.is_paused());
+        assert_eq!(time.group_scale("ui"), 1.);
+        assert!(!time.is_group_paused("ui"));
+        assert_eq!(time.effective_scale(None), Some(1.));
+        assert_eq!(time.effective_scale(Some("ui")), Some(1.));
+
+        time.set_scale(0.5);
+        time.set_group_scale("gameplay", 0.5);
+        assert_eq!(time.effective_scale(None), Some(0.5));
+        assert_eq!(time.effective_scale(Some("ui")), Some(0.5));
+        assert_eq!(time.effective_scale(Some("gameplay")), Some(0.25));
+
+        time.set_group_paused("gameplay", true);
+        assert!(time.is_group_paused("gameplay"));
+        assert_eq!(time.group_scale("gameplay"), 0.5);
+        assert_eq!(time.effective_scale(Some("gameplay")), None);
+        assert_eq!(time.effective_scale(Some("ui")), Some(0.5));
+
+        time.set_paused(true);
+        assert_eq!(time.effective_scale(None), None);
+        assert_eq!(time.effective_scale(Some("ui")), None);
+
+        time.set_scale(-1.);
+        assert_eq!(time.scale(), 0.);
+
+        time.reset();
+        assert!(!time.is_paused());
+        assert_eq!(time.effective_scale(Some("gameplay")), Some(1.));
+    }
+
+    #[test]
+    fn tweening_time_sources() {
+        let mut time = TweeningTime::default()
+            .with_default_source(TimeSource::Fixed)
+            .with_fixed_step(Duration::from_millis(20));
+        assert_eq!(time.default_source(), TimeSource::Fixed);
+        assert_eq!(time.fixed_step(), Duration::from_millis(20));
+
+        let mut bevy_time = Time::default();
+        bevy_time.update();
+        let last_update = bevy_time.last_update().unwrap();
+        bevy_time.set_relative_speed(0.5);
+        bevy_time.update_with_instant(last_update + Duration::from_millis(100));
+        assert_eq!(
+            time.delta(Some(TimeSource::Virtual), &bevy_time),
+            Duration::from_millis(50)
+        );
+        assert_eq!(
+            time.delta(Some(TimeSource::Real), &bevy_time),
+            Duration::from_millis(100)
+        );
+
+        // Fixed steps accumulate the left over time deterministically
+        for (delta, fixed_delta, accumulator) in
+            [(15, 0, 15), (30, 40, 5), (16, 20, 1), (19, 20, 0)]
+        {
+            time.update_fixed(Duration::from_millis(delta));
+            assert_eq!(
+                time.delta(None, &bevy_time),
+                Duration::from_millis(fixed_delta)
+            );
+            assert_eq!(time.fixed_accumulator(), Duration::from_millis(accumulator));
+        }
+
+        assert_eq!(
+            time.delta(Some(TimeSource::Clock), &bevy_time),
+            Duration::ZERO
+        );
+        time.set_clock_delta(Duration::from_millis(7));
+        assert_eq!(
+            time.delta(Some(TimeSource::Clock), &bevy_time),
+            Duration::from_millis(7)
+        );
+    }
+
+    #[test]
+    fn tweening_time_advance_fixed() {
+        let mut time = TweeningTime::default()
+            .with_default_source(TimeSource::Fixed)
+            .with_fixed_step(Duration::from_millis(20));
+        let bevy_time = Time::default();
+
+        time.update_fixed(Duration::from_millis(30));
+        assert_eq!(time.delta(None, &bevy_time), Duration::from_millis(20));
+        assert_eq!(time.fixed_accumulator(), Duration::from_millis(10));
+
+        // Once steps are reported, the frame time is ignored
+        time.advance_fixed(Duration::from_millis(20));
+        time.advance_fixed(Duration::from_millis(20));
+        time.update_fixed(Duration::from_millis(5));
+        assert_eq!(time.delta(None, &bevy_time), Duration::from_millis(40));
+        time.update_fixed(Duration::from_millis(100));
+        assert_eq!(time.delta(None, &bevy_time), Duration::ZERO);
+        time.advance_fixed(Duration::from_millis(20));
+        time.update_fixed(Duration::ZERO);
+        assert_eq!(time.delta(None, &bevy_time), Duration::from_millis(20));
+    }
+
+    #[test]
+    #[should_panic]
+    fn tweening_time_zero_fixed_step() {
+        let _ = TweeningTime::default().with_fixed_step(Duration::ZERO);
+    }
+}
@@ -1,6 +1,6 @@
-use std::{ops::DerefMut, time::Duration};
+use std::{ops::DerefMut, sync::Arc, time::Duration};
 
-use bevy::prelude::*;
+use bevy::{ecs::event::Event, prelude::*, utils::HashMap};
 
 #[cfg(feature = "bevy_asset")]
 use bevy::asset::{Asset, HandleId};
@@ -25,8 +25,8 @@ use crate::{EaseMethod, Lens, RepeatCount, RepeatStrategy, TweeningDirection};
 /// implement [`From`]:
 /// ```no_run
 /// # use std::time::Duration;
-/// # use bevy::prelude::{Entity, Events, Mut, Transform};
-/// # use bevy_tweening::{BoxedTweenable, Sequence, Tweenable, TweenCompleted, TweenState, Targetable, TotalDuration};
+/// # use bevy::prelude::{Entity, Transform};
+/// # use bevy_tweening::{BoxedTweenable, Sequence, Tweenable, TweenEvents, TweenState, Targetable, TotalDuration};
 /// #
 /// # struct MyTweenable;
 /// # impl Tweenable<Transform> for MyTweenable {
@@ -34,7 +34,7 @@ use crate::{EaseMethod, Lens, RepeatCount, RepeatStrategy, TweeningDirection};
 /// #     fn total_duration(&self) -> TotalDuration  { unimplemented!() }
 /// #     fn set_elapsed(&mut self, elapsed: Duration)  { unimplemented!() }
 /// #     fn elapsed(&self) -> Duration  { unimplemented!() }
-/// #     fn tick<'a>(&mut self, delta: Duration, target: &'a mut dyn Targetable<Transform>, entity: Entity, events: &mut Mut<Events<TweenCompleted>>) -> TweenState  { unimplemented!() }
+/// #     fn tick(&mut self, delta: Duration, target: &mut dyn Targetable<Transform>, entity: Entity, events: &mut TweenEvents<'_>) -> TweenState  { unimplemented!() }
 /// #     fn rewind(&mut self) { unimplemented!() }
 /// # }
 ///
@@ -71,7 +71,8 @@ pub enum TweenState {
 /// Event raised when a tween completed.
 ///
 /// This event is raised when a tween completed. When looping, this is raised
-/// once per iteration. In case the animation direction changes
+/// once per iteration. A [`Marker`] being reached raises a [`MarkerReached`]
+/// event instead. In case the animation direction changes
 /// ([`RepeatStrategy::MirroredRepeat`]), an iteration corresponds to a single
 /// progress from one endpoint to the other, whatever the direction. Therefore a
 /// complete cycle start -> end -> start counts as 2 iterations and raises 2
@@ -99,6 +100,125 @@ pub struct TweenCompleted {
     pub user_data: u64,
 }
 
+/// Event raised when a [`Marked`] tweenable reaches one of its [`Marker`].
+///
+/// Unlike [`TweenCompleted`], this event doesn't mark the end of an iteration.
+/// It's only raised for the markers enabled with [`Marker::with_event()`], and
+/// like the events of other types it's sent once the animator systems ran.
+#[derive(Copy, Clone)]
+pub struct MarkerReached {
+    /// The [`Entity`] the marked tweenable and its animator are attached to.
+    pub entity: Entity,
+    /// The opaque value set by the user with [`Marker::with_event()`], used to
+    /// identify the particular marker which raised this event.
+    pub user_data: u64,
+}
+
+/// Type of a world operation deferred by a tweenable while ticking.
+type DeferredOp = Box<dyn FnOnce(&mut World) + Send + Sync + 'static>;
+
+/// Type of a function raising a typed completion event.
+///
+/// See [`Tween::with_completed_payload()`] for usage.
+type PayloadSender = dyn Fn(&mut TweenEvents<'_>) + Send + Sync + 'static;
+
+/// Make a [`PayloadSender`] raising a clone of the given event each time it's
+/// invoked.
+fn payload_sender<E: Event + Clone>(event: E) -> Box<PayloadSender> {
+    Box::new(move |events| events.send_payload(event.clone()))
+}
+
+/// Destination of the events raised by tweenables while ticking.
+///
+/// [`TweenCompleted`] events are sent immediately. Events of any other type,
+/// like the payloads set with [`Tween::with_completed_payload()`], are
+/// deferred and sent once the animator systems ran, like [`Commands`] are. The
+/// type of those events must be registered with
+/// [`TweenEventPlugin`](crate::TweenEventPlugin). Callbacks needing a mutable
+/// access to the [`World`], like the ones set with
+/// [`Tween::with_completed_deferred()`], are deferred the same way.
+///
+/// # Migrating from `Mut<Events<TweenCompleted>>`
+///
+/// [`Tweenable::tick()`] used to take the [`TweenCompleted`] events directly.
+/// Custom tweenables only need to change the type of the `events` parameter,
+/// since [`send()`] keeps sending [`TweenCompleted`] events immediately. Code
+/// ticking a tweenable directly can wrap its events queue:
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::{ecs::event::Events, prelude::*};
+/// # use bevy_tweening::{lens::*, *};
+/// # let mut tween = Tween::new(EaseMethod::Linear, Duration::from_secs(1),
+/// #     TransformPositionLens { start: Vec3::ZERO, end: Vec3::ONE });
+/// # let mut transform = Transform::default();
+/// # struct Target<'a>(&'a mut Transform);
+/// # impl<'a> Targetable<Transform> for Target<'a> {
+/// #     fn target_mut(&mut self) -> &mut Transform { self.0 }
+/// # }
+/// # let mut target = Target(&mut transform);
+/// # let entity = Entity::from_raw(0);
+/// let mut completed = Events::<TweenCompleted>::default();
+/// let mut events = TweenEvents::from(&mut completed);
+/// tween.tick(Duration::from_millis(100), &mut target, entity, &mut events);
+/// ```
+///
+/// Operations deferred with [`defer()`] and [`send_payload()`] are only applied
+/// by the animator systems, and dropped otherwise.
+///
+/// [`send()`]: TweenEvents::send
+/// [`defer()`]: TweenEvents::defer
+/// [`send_payload()`]: TweenEvents::send_payload
+pub struct TweenEvents<'a> {
+    completed: &'a mut Events<TweenCompleted>,
+    deferred: Vec<DeferredOp>,
+}
+
+impl<'a> TweenEvents<'a> {
+    /// Create a new event destination sending [`TweenCompleted`] events to the
+    /// given queue.
+    #[must_use]
+    pub fn new(completed: &'a mut Events<TweenCompleted>) -> Self {
+        Self {
+            completed,
+            deferred: vec![],
+        }
+    }
+
+    /// Send a [`TweenCompleted`] event.
+    pub fn send(&mut self, event: TweenCompleted) {
+        self.completed.send(event);
+    }
+
+    /// Send an event of any type, once the animator systems ran.
+    pub fn send_payload<E: Event>(&mut self, event: E) {
+        self.deferred
+            .push(Box::new(move |world: &mut World| world.send_event(event)));
+    }
+
+    /// Defer an operation on the world until the animator systems ran.
+    pub fn defer(&mut self, op: impl FnOnce(&mut World) + Send + Sync + 'static) {
+        self.deferred.push(Box::new(op));
+    }
+
+    /// Defer invoking the given callback until the animator systems ran.
+    fn defer_callback(&mut self, callback: &Arc<DeferredCallback>, entity: Entity) {
+        let callback = Arc::clone(callback);
+        self.defer(move |world| callback(entity, world));
+    }
+
+    /// Take all the deferred operations, to apply them to the world.
+    pub(crate) fn take_deferred(&mut self) -> Vec<DeferredOp> {
+        std::mem::take(&mut self.deferred)
+    }
+}
+
+impl<'a> From<&'a mut Events<TweenCompleted>> for TweenEvents<'a> {
+    fn from(completed: &'a mut Events<TweenCompleted>) -> Self {
+        Self::new(completed)
+    }
+}
+
 /// Calculate the progress fraction in \[0:1\] of the ratio between two
 /// [`Duration`]s.
 fn fraction_progress(n: Duration, d: Duration) -> f32 {
@@ -110,6 +230,7 @@ fn fraction_progress(n: Duration, d: Duration) -> f32 {
 struct AnimClock {
     elapsed: Duration,
     duration: Duration,
+    repeat_count: RepeatCount,
     total_duration: TotalDuration,
     strategy: RepeatStrategy,
 }
@@ -119,19 +240,64 @@ impl AnimClock {
         Self {
             elapsed: Duration::ZERO,
             duration,
+            repeat_count: RepeatCount::default(),
             total_duration: compute_total_duration(duration, RepeatCount::default()),
             strategy: RepeatStrategy::default(),
         }
     }
 
+    fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+        self.total_duration = compute_total_duration(duration, self.repeat_count);
+    }
+
+    fn set_repeat_count(&mut self, count: RepeatCount) {
+        self.repeat_count = count;
+        self.total_duration = compute_total_duration(self.duration, count);
+    }
+
     fn tick(&mut self, tick: Duration) -> (TweenState, i32) {
         self.set_elapsed(self.elapsed.saturating_add(tick))
     }
 
     fn times_completed(&self) -> u32 {
+        if self.duration.is_zero() {
+            return 0;
+        }
         (self.elapsed.as_nanos() / self.duration.as_nanos()) as u32
     }
 
+    /// Get the index of the current iteration, and the elapsed time inside that
+    /// iteration.
+    ///
+    /// Once completed, the clock reports the end of the last iteration, instead
+    /// of the start of a new iteration which would never play.
+    fn position(&self) -> (u32, Duration) {
+        let mut iteration = self.times_completed();
+        let mut local = self.elapsed - self.duration * iteration;
+        if iteration > 0 && local.is_zero() && self.state() == TweenState::Completed {
+            iteration -= 1;
+            local = self.duration;
+        }
+        (iteration, local)
+    }
+
+    /// Check if the current iteration plays in the opposite direction, which
+    /// happens every other iteration with [`RepeatStrategy::MirroredRepeat`].
+    fn is_mirrored(&self) -> bool {
+        self.strategy == RepeatStrategy::MirroredRepeat && self.position().0 & 1 != 0
+    }
+
+    /// Get the direction of the current iteration, given the direction of the
+    /// first one.
+    fn direction(&self, direction: TweeningDirection) -> TweeningDirection {
+        if self.is_mirrored() {
+            !direction
+        } else {
+            direction
+        }
+    }
+
     fn set_elapsed(&mut self, elapsed: Duration) -> (TweenState, i32) {
         let old_times_completed = self.times_completed();
 
@@ -223,6 +389,16 @@ impl<'a, T: Component> Targetable<T> for ComponentTarget<'a, T> {
     }
 }
 
+/// Target wrapping a plain value, used to animate a scratch copy of a
+/// component or asset.
+pub(crate) struct ValueTarget<'a, T>(pub &'a mut T);
+
+impl<'a, T> Targetable<T> for ValueTarget<'a, T> {
+    fn target_mut(&mut self) -> &mut T {
+        self.0
+    }
+}
+
 #[cfg(feature = "bevy_asset")]
 pub struct AssetTarget<'a, T: Asset> {
     assets: ResMut<'a, Assets<T>>,
@@ -293,6 +469,39 @@ pub trait Tweenable<T>: Send + Sync {
     /// [`duration()`]: Tweenable::duration
     fn elapsed(&self) -> Duration;
 
+    /// Set the playback direction of the animation.
+    ///
+    /// [`TweeningDirection::Backward`] plays the animation reversed, from its
+    /// end to its start. For a [`Tween`] this reverses the mapping of the
+    /// progress to the ratio passed to the lens, while collections like
+    /// [`Sequence`] and [`Tracks`] play all their children backward.
+    ///
+    /// With [`RepeatStrategy::MirroredRepeat`], the direction alternates on each
+    /// iteration, and this sets the direction of the current one.
+    ///
+    /// Changing the direction doesn't change the elapsed time nor the progress,
+    /// so the animation jumps to the mirrored point of its current iteration.
+    /// Like seeking, this doesn't apply any change to the target. To force the
+    /// change to apply, call [`tick()`] with a `delta` of `Duration::ZERO`.
+    ///
+    /// The default implementation ignores the direction, for tweenables which
+    /// can only play forward.
+    ///
+    /// [`tick()`]: Tweenable::tick
+    fn set_direction(&mut self, direction: TweeningDirection) {
+        let _ = direction;
+    }
+
+    /// Get the playback direction of the current iteration.
+    ///
+    /// See [`set_direction()`] for details. The default implementation always
+    /// returns [`TweeningDirection::Forward`].
+    ///
+    /// [`set_direction()`]: Tweenable::set_direction
+    fn direction(&self) -> TweeningDirection {
+        TweeningDirection::Forward
+    }
+
     /// Tick the animation, advancing it by the given delta time and mutating
     /// the given target component or asset.
     ///
@@ -307,16 +516,63 @@ pub trait Tweenable<T>: Send + Sync {
     /// operations like [`rewind()`] or [`set_progress()`] whose effect is
     /// otherwise only visible on target on next frame.
     ///
+    /// Any event raised while ticking, like [`TweenCompleted`], is sent to the
+    /// given [`TweenEvents`].
+    ///
     /// [`rewind()`]: Tweenable::rewind
     /// [`set_progress()`]: Tweenable::set_progress
-    fn tick<'a>(
+    fn tick(
         &mut self,
         delta: Duration,
-        target: &'a mut dyn Targetable<T>,
+        target: &mut dyn Targetable<T>,
         entity: Entity,
-        events: &mut Mut<Events<TweenCompleted>>,
+        events: &mut TweenEvents<'_>,
     ) -> TweenState;
 
+    /// Tick the animation backward in time, moving it back by the given delta
+    /// time toward its start, and mutating the given target component or asset.
+    ///
+    /// This rewinds the playback, so the target goes back through the same
+    /// states it went through when playing forward. This differs from playing
+    /// backward with [`set_direction()`], which plays the animation reversed
+    /// but still forward in time.
+    ///
+    /// Ticking backward doesn't raise any [`TweenCompleted`] event nor invoke
+    /// any completion callback, including when reaching the start. Those are
+    /// raised again if the animation plays forward again past their point.
+    ///
+    /// This returns [`TweenState::Completed`] once the animation reached its
+    /// start, and can't be ticked backward anymore, or [`TweenState::Active`]
+    /// otherwise. Like [`tick()`], a zero `delta` is valid and applies the
+    /// current state to the target.
+    ///
+    /// The default implementation seeks the animation with [`set_elapsed()`],
+    /// then applies its state with a zero-delta [`tick()`].
+    ///
+    /// [`set_direction()`]: Tweenable::set_direction
+    /// [`tick()`]: Tweenable::tick
+    /// [`set_elapsed()`]: Tweenable::set_elapsed
+    fn tick_backward(
+        &mut self,
+        delta: Duration,
+        target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut TweenEvents<'_>,
+    ) -> TweenState {
+        let elapsed = self.elapsed();
+        if !delta.is_zero() && elapsed.is_zero() {
+            return TweenState::Completed;
+        }
+        let elapsed = elapsed.saturating_sub(delta);
+        self.set_elapsed(elapsed);
+        self.tick(Duration::ZERO, target, entity, events);
+        if elapsed.is_zero() {
+            TweenState::Completed
+        } else {
+            TweenState::Active
+        }
+    }
+
     /// Rewind the animation to its starting state.
     ///
     /// Note that the starting state depends on the current direction. For
@@ -380,6 +636,8 @@ macro_rules! impl_boxed {
 impl_boxed!(Tween<T>);
 impl_boxed!(Sequence<T>);
 impl_boxed!(Tracks<T>);
+impl_boxed!(Timeline<T>);
+impl_boxed!(Marked<T>);
 impl_boxed!(Delay<T>);
 
 /// Type of a callback invoked when a [`Tween`] or [`Delay`] has completed.
@@ -387,6 +645,12 @@ impl_boxed!(Delay<T>);
 /// See [`Tween::set_completed()`] or [`Delay::set_completed()`] for usage.
 pub type CompletedCallback<T> = dyn Fn(Entity, &T) + Send + Sync + 'static;
 
+/// Type of a deferred callback invoked with a mutable access to the [`World`]
+/// when a [`Tween`], [`Delay`], or [`Marker`] has completed or was reached.
+///
+/// See [`Tween::with_completed_deferred()`] for usage.
+pub type DeferredCallback = dyn Fn(Entity, &mut World) + Send + Sync + 'static;
+
 /// Single tweening animation instance.
 pub struct Tween<T> {
     ease_function: EaseMethod,
@@ -394,7 +658,9 @@ pub struct Tween<T> {
     direction: TweeningDirection,
     lens: Box<dyn Lens<T> + Send + Sync + 'static>,
     on_completed: Option<Box<CompletedCallback<Tween<T>>>>,
+    on_completed_deferred: Option<Arc<DeferredCallback>>,
     event_data: Option<u64>,
+    payload: Option<Box<PayloadSender>>,
 }
 
 impl<T: 'static> Tween<T> {
@@ -458,7 +724,9 @@ impl<T> Tween<T> {
             direction: TweeningDirection::Forward,
             lens: Box::new(lens),
             on_completed: None,
+            on_completed_deferred: None,
             event_data: None,
+            payload: None,
         }
     }
 
@@ -547,35 +815,34 @@ impl<T> Tween<T> {
     /// reverses the mapping, which effectively makes the tween play reversed,
     /// going from end to start.
     ///
-    /// Changing the direction doesn't change any target state, nor any progress
-    /// of the tween. Only the direction of animation from this moment
-    /// potentially changes. To force a target state change, call
-    /// [`Tweenable::tick()`] with a zero delta (`Duration::ZERO`).
-    pub fn set_direction(&mut self, direction: TweeningDirection) {
-        self.direction = direction;
+    /// See [`Tweenable::set_direction()`].
+    #[must_use]
+    pub fn with_direction(mut self, direction: TweeningDirection) -> Self {
+        self.set_direction(direction);
+        self
     }
 
     /// Set the playback direction of the tween.
     ///
-    /// See [`Tween::set_direction()`].
-    #[must_use]
-    pub fn with_direction(mut self, direction: TweeningDirection) -> Self {
-        self.direction = direction;
-        self
+    /// This forwards to [`Tweenable::set_direction()`], so it can be called
+    /// without importing the trait.
+    pub fn set_direction(&mut self, direction: TweeningDirection) {
+        Tweenable::set_direction(self, direction);
     }
 
     /// The current animation direction.
     ///
-    /// See [`TweeningDirection`] for details.
+    /// This forwards to [`Tweenable::direction()`], so it can be called
+    /// without importing the trait.
     #[must_use]
     pub fn direction(&self) -> TweeningDirection {
-        self.direction
+        Tweenable::direction(self)
     }
 
     /// Set the number of times to repeat the animation.
     #[must_use]
     pub fn with_repeat_count(mut self, count: impl Into<RepeatCount>) -> Self {
-        self.clock.total_duration = compute_total_duration(self.clock.duration, count.into());
+        self.clock.set_repeat_count(count.into());
         self
     }
 
@@ -609,6 +876,70 @@ impl<T> Tween<T> {
         self.on_completed = None;
     }
 
+    /// Set a deferred callback invoked when the tween completes.
+    ///
+    /// Unlike [`with_completed()`], the callback receives a mutable access to
+    /// the [`World`], allowing it for example to despawn the entity, insert
+    /// some components, or start a new animation. To allow this, the callback
+    /// is not invoked immediately while ticking, but once the animator systems
+    /// ran, like [`Commands`] are.
+    ///
+    /// Only non-looping tweenables can complete.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::{math::Vec3, prelude::*};
+    /// # use std::time::Duration;
+    /// #[derive(Component)]
+    /// struct Opened;
+    ///
+    /// let tween = Tween::new(
+    ///     // [...]
+    /// #    EaseFunction::QuadraticInOut,
+    /// #    Duration::from_secs(1),
+    /// #    TransformPositionLens {
+    /// #        start: Vec3::ZERO,
+    /// #        end: Vec3::new(3.5, 0., 0.),
+    /// #    },
+    /// )
+    /// .with_completed_deferred(|entity, world| {
+    ///   world.entity_mut(entity).insert(Opened);
+    /// });
+    /// ```
+    ///
+    /// [`with_completed()`]: Tween::with_completed
+    #[must_use]
+    pub fn with_completed_deferred<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(Entity, &mut World) + Send + Sync + 'static,
+    {
+        self.on_completed_deferred = Some(Arc::new(callback));
+        self
+    }
+
+    /// Set a deferred callback invoked when the tween completes.
+    ///
+    /// See [`with_completed_deferred()`] for details.
+    ///
+    /// [`with_completed_deferred()`]: Tween::with_completed_deferred
+    pub fn set_completed_deferred<C>(&mut self, callback: C)
+    where
+        C: Fn(Entity, &mut World) + Send + Sync + 'static,
+    {
+        self.on_completed_deferred = Some(Arc::new(callback));
+    }
+
+    /// Clear the deferred callback invoked when the tween completes.
+    ///
+    /// See also [`set_completed_deferred()`].
+    ///
+    /// [`set_completed_deferred()`]: Tween::set_completed_deferred
+    pub fn clear_completed_deferred(&mut self) {
+        self.on_completed_deferred = None;
+    }
+
     /// Enable or disable raising a completed event.
     ///
     /// If enabled, the tween will raise a [`TweenCompleted`] event when the
@@ -631,6 +962,71 @@ impl<T> Tween<T> {
     pub fn clear_completed_event(&mut self) {
         self.event_data = None;
     }
+
+    /// Enable raising a completed event of a custom type.
+    ///
+    /// If enabled, the tween will raise a clone of the given event each time it
+    /// completes, in addition to any [`TweenCompleted`] event enabled with
+    /// [`with_completed_event()`]. Unlike the opaque user data of
+    /// [`TweenCompleted`], the event can hold any meaningful payload. The type
+    /// of the event must be registered with
+    /// [`TweenEventPlugin`](crate::TweenEventPlugin). See [`TweenEvents`] for
+    /// details about when the event is raised.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::{ecs::event::EventReader, math::Vec3};
+    /// # use std::time::Duration;
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// enum DoorEvent {
+    ///     Opened,
+    ///     Closed,
+    /// }
+    ///
+    /// let tween = Tween::new(
+    ///     // [...]
+    /// #    EaseFunction::QuadraticInOut,
+    /// #    Duration::from_secs(1),
+    /// #    TransformPositionLens {
+    /// #        start: Vec3::ZERO,
+    /// #        end: Vec3::new(3.5, 0., 0.),
+    /// #    },
+    /// )
+    /// .with_completed_payload(DoorEvent::Opened);
+    ///
+    /// fn my_system(mut reader: EventReader<DoorEvent>) {
+    ///   for ev in reader.iter() {
+    ///     assert_eq!(*ev, DoorEvent::Opened);
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// [`with_completed_event()`]: Tween::with_completed_event
+    #[must_use]
+    pub fn with_completed_payload<E: Event + Clone>(mut self, event: E) -> Self {
+        self.payload = Some(payload_sender(event));
+        self
+    }
+
+    /// Enable raising a completed event of a custom type.
+    ///
+    /// See [`with_completed_payload()`] for details.
+    ///
+    /// [`with_completed_payload()`]: Tween::with_completed_payload
+    pub fn set_completed_payload<E: Event + Clone>(&mut self, event: E) {
+        self.payload = Some(payload_sender(event));
+    }
+
+    /// Clear the custom event sent when the tween completes.
+    ///
+    /// See also [`set_completed_payload()`].
+    ///
+    /// [`set_completed_payload()`]: Tween::set_completed_payload
+    pub fn clear_completed_payload(&mut self) {
+        self.payload = None;
+    }
 }
 
 impl<T> Tweenable<T> for Tween<T> {
@@ -650,33 +1046,40 @@ impl<T> Tweenable<T> for Tween<T> {
         self.clock.elapsed()
     }
 
-    fn tick<'a>(
+    fn set_direction(&mut self, direction: TweeningDirection) {
+        // The stored direction is the one of the first iteration; mirrored
+        // iterations play in the opposite direction.
+        self.direction = self.clock.direction(direction);
+    }
+
+    fn direction(&self) -> TweeningDirection {
+        self.clock.direction(self.direction)
+    }
+
+    fn tick(
         &mut self,
         delta: Duration,
-        target: &'a mut dyn Targetable<T>,
+        target: &mut dyn Targetable<T>,
         entity: Entity,
-        events: &mut Mut<Events<TweenCompleted>>,
+        events: &mut TweenEvents<'_>,
     ) -> TweenState {
-        if self.clock.state() == TweenState::Completed {
+        // A zero delta is still allowed to apply the current state, even once
+        // completed.
+        if !delta.is_zero() && self.clock.state() == TweenState::Completed {
             return TweenState::Completed;
         }
 
         // Tick the animation clock
         let (state, times_completed) = self.clock.tick(delta);
-        let (progress, times_completed_for_direction) = match state {
-            TweenState::Active => (self.progress(), times_completed),
-            TweenState::Completed => (1., times_completed.max(1) - 1), // ignore last
+        let progress = match state {
+            TweenState::Active => self.progress(),
+            TweenState::Completed => 1.,
         };
-        if self.clock.strategy == RepeatStrategy::MirroredRepeat
-            && times_completed_for_direction & 1 != 0
-        {
-            self.direction = !self.direction;
-        }
 
         // Apply the lens, even if the animation finished, to ensure the state is
         // consistent
         let mut factor = progress;
-        if self.direction.is_backward() {
+        if self.direction().is_backward() {
             factor = 1. - factor;
         }
         let factor = self.ease_function.sample(factor);
@@ -691,39 +1094,175 @@ impl<T> Tweenable<T> for Tween<T> {
                     user_data: *user_data,
                 });
             }
+            if let Some(payload) = &self.payload {
+                payload(events);
+            }
             if let Some(cb) = &self.on_completed {
                 cb(entity, self);
             }
+            if let Some(cb) = &self.on_completed_deferred {
+                events.defer_callback(cb, entity);
+            }
         }
 
         state
     }
 
     fn rewind(&mut self) {
-        if self.clock.strategy == RepeatStrategy::MirroredRepeat {
-            // In mirrored mode, direction alternates each loop. To reset to the original
-            // direction on Tween creation, we count the number of completions, ignoring the
-            // last one if the Tween is currently in TweenState::Completed because that one
-            // freezes all parameters.
-            let mut times_completed = self.clock.times_completed();
-            if self.clock.state() == TweenState::Completed {
-                debug_assert!(times_completed > 0);
-                times_completed -= 1;
-            }
-            if times_completed & 1 != 0 {
-                self.direction = !self.direction;
-            }
-        }
+        // In mirrored mode, the direction alternates each iteration, and is derived
+        // from the elapsed time, so resetting the clock also restores the original
+        // direction.
         self.clock.reset();
     }
 }
 
+/// Get the duration of a child tweenable inside a [`Sequence`] or [`Tracks`],
+/// including any repeat.
+///
+/// A child repeating infinitely never completes, so occupies an infinite
+/// duration, represented by [`Duration::MAX`].
+fn span_duration<T>(tweenable: &dyn Tweenable<T>) -> Duration {
+    match tweenable.total_duration() {
+        TotalDuration::Finite(duration) => duration,
+        TotalDuration::Infinite => Duration::MAX,
+    }
+}
+
+/// Compute the total duration of a [`Sequence`] or [`Tracks`], which is
+/// infinite if any child repeats infinitely.
+fn composite_total_duration<'a, T: 'a>(
+    clock: &AnimClock,
+    mut children: impl Iterator<Item = &'a BoxedTweenable<T>>,
+) -> TotalDuration {
+    if children.any(|child| child.total_duration() == TotalDuration::Infinite) {
+        TotalDuration::Infinite
+    } else {
+        clock.total_duration
+    }
+}
+
+/// Reverse the direction of a child tweenable.
+fn flip<T>(tweenable: &mut BoxedTweenable<T>) {
+    tweenable.set_direction(!tweenable.direction());
+}
+
+/// Orient the children of a [`Sequence`] or [`Tracks`] for the given playback
+/// direction of their parent, given the direction they're currently oriented
+/// for.
+///
+/// Playing a collection backward plays each of its children backward, so
+/// changing direction flips the direction of all the children.
+fn orient_children<T>(
+    children: &mut [BoxedTweenable<T>],
+    oriented: &mut TweeningDirection,
+    direction: TweeningDirection,
+) {
+    if *oriented != direction {
+        children.iter_mut().for_each(flip);
+        *oriented = direction;
+    }
+}
+
+/// Common playback logic of the [`Tweenable`] collections, which handles
+/// repeating the entire collection.
+trait Composite<T> {
+    /// Get the clock of the collection.
+    fn clock(&mut self) -> &mut AnimClock;
+
+    /// Tick the children by the given delta, from the given position inside
+    /// the current iteration.
+    fn tick_children(
+        &mut self,
+        position: Duration,
+        delta: Duration,
+        target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut TweenEvents<'_>,
+    );
+
+    /// Rewind all the children, to prepare for a new iteration in the current
+    /// direction.
+    fn rewind_children(&mut self);
+}
+
+/// Tick a [`Composite`] collection, splitting the delta time at iteration
+/// boundaries.
+///
+/// Each iteration ticks the children, whatever the direction, so they raise
+/// their events and invoke their callbacks each time they complete.
+fn tick_composite<T>(
+    composite: &mut impl Composite<T>,
+    mut delta: Duration,
+    target: &mut dyn Targetable<T>,
+    entity: Entity,
+    events: &mut TweenEvents<'_>,
+) -> TweenState {
+    let clock = composite.clock();
+    if !delta.is_zero() && clock.state() == TweenState::Completed {
+        return TweenState::Completed;
+    }
+
+    loop {
+        let clock = composite.clock();
+        let (_, position) = clock.position();
+        let mut step = delta.min(clock.duration - position);
+        if let TotalDuration::Finite(total_duration) = clock.total_duration {
+            step = step.min(total_duration.saturating_sub(clock.elapsed));
+        }
+        composite.tick_children(position, step, target, entity, events);
+        let (state, times_completed) = composite.clock().tick(step);
+        delta -= step;
+
+        if state == TweenState::Completed {
+            return TweenState::Completed;
+        }
+
+        // A zero-duration iteration never advances, so stop once the children
+        // were applied instead of looping forever when repeating infinitely
+        if composite.clock().duration.is_zero() {
+            return TweenState::Active;
+        }
+
+        // Crossed into a new iteration. If there's no time left, loop once more
+        // anyway to apply the start state of that iteration, like a single looping
+        // tween does.
+        if times_completed > 0 {
+            composite.rewind_children();
+        } else if delta.is_zero() {
+            return TweenState::Active;
+        }
+    }
+}
+
 /// A sequence of tweens played back in order one after the other.
+///
+/// Playing the sequence backward with
+/// [`set_direction()`](Tweenable::set_direction) plays its children in reverse
+/// order, each one in the opposite of its own direction, so that a sequence
+/// animating some entity in can animate it back out.
+///
+/// The sequence can be repeated as a whole with
+/// [`with_repeat_count()`](Sequence::with_repeat_count), optionally playing
+/// back and forth with [`RepeatStrategy::MirroredRepeat`]. Children raise
+/// their events and invoke their callbacks each time they complete, whatever
+/// the direction the sequence plays in.
+///
+/// Seeking with [`set_elapsed()`](Tweenable::set_elapsed) or
+/// [`set_progress()`](Tweenable::set_progress) moves all children to their
+/// state at that time. The next tick then applies to the target the state of
+/// all children, and not only the active one, so that the target ends up in
+/// the same state as if the sequence had played continuously up to that time.
 pub struct Sequence<T> {
     tweens: Vec<BoxedTweenable<T>>,
     index: usize,
-    duration: Duration,
-    elapsed: Duration,
+    clock: AnimClock,
+    /// Direction of the first iteration.
+    direction: TweeningDirection,
+    /// Direction the children are currently oriented for.
+    oriented: TweeningDirection,
+    /// Set after seeking, until the state of the children other than the
+    /// active one is applied on next tick.
+    pending_seek: bool,
 }
 
 impl<T> Sequence<T> {
@@ -737,26 +1276,30 @@ impl<T> Sequence<T> {
         let duration = tweens
             .iter()
             .map(AsRef::as_ref)
-            .map(Tweenable::duration)
-            .sum();
+            .map(span_duration)
+            .fold(Duration::ZERO, Duration::saturating_add);
         Self {
             tweens,
             index: 0,
-            duration,
-            elapsed: Duration::ZERO,
+            clock: AnimClock::new(duration),
+            direction: TweeningDirection::Forward,
+            oriented: TweeningDirection::Forward,
+            pending_seek: false,
         }
     }
 
     /// Create a new sequence containing a single tween.
     #[must_use]
     pub fn from_single(tween: impl Tweenable<T> + 'static) -> Self {
-        let duration = tween.duration();
+        let duration = span_duration(&tween);
         let boxed: BoxedTweenable<T> = Box::new(tween);
         Self {
             tweens: vec![boxed],
             index: 0,
-            duration,
-            elapsed: Duration::ZERO,
+            clock: AnimClock::new(duration),
+            direction: TweeningDirection::Forward,
+            oriented: TweeningDirection::Forward,
+            pending_seek: false,
         }
     }
 
@@ -766,23 +1309,93 @@ impl<T> Sequence<T> {
         Self {
             tweens: Vec::with_capacity(capacity),
             index: 0,
-            duration: Duration::ZERO,
-            elapsed: Duration::ZERO,
+            clock: AnimClock::new(Duration::ZERO),
+            direction: TweeningDirection::Forward,
+            oriented: TweeningDirection::Forward,
+            pending_seek: false,
         }
     }
 
     /// Append a [`Tweenable`] to this sequence.
+    ///
+    /// The tweenable plays in its own direction when the sequence plays
+    /// forward, whatever the current direction of the sequence.
     #[must_use]
     pub fn then(mut self, tween: impl Tweenable<T> + 'static) -> Self {
-        self.duration += tween.duration();
-        self.tweens.push(Box::new(tween));
+        self.clock
+            .set_duration(self.clock.duration.saturating_add(span_duration(&tween)));
+        let mut tween: BoxedTweenable<T> = Box::new(tween);
+        if self.oriented.is_backward() {
+            flip(&mut tween);
+            self.tweens.push(tween);
+            self.seek_children();
+        } else {
+            self.tweens.push(tween);
+        }
         self
     }
 
-    /// Index of the current active tween in the sequence.
-    #[must_use]
-    pub fn index(&self) -> usize {
-        self.index.min(self.tweens.len() - 1)
+    /// Set the number of times to repeat the entire sequence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::math::Vec3;
+    /// # use std::time::Duration;
+    /// # let tween1 = Tween::new(EaseMethod::Linear, Duration::from_secs(1),
+    /// #     TransformPositionLens { start: Vec3::ZERO, end: Vec3::X });
+    /// # let tween2 = Tween::new(EaseMethod::Linear, Duration::from_secs(1),
+    /// #     TransformPositionLens { start: Vec3::X, end: Vec3::Y });
+    /// // Play the two tweens one after the other, then back in reverse order,
+    /// // and repeat forever.
+    /// let idle = tween1
+    ///     .then(tween2)
+    ///     .with_repeat_count(RepeatCount::Infinite)
+    ///     .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+    /// ```
+    #[must_use]
+    pub fn with_repeat_count(mut self, count: impl Into<RepeatCount>) -> Self {
+        self.clock.set_repeat_count(count.into());
+        self
+    }
+
+    /// Choose how the sequence behaves upon a repetition.
+    #[must_use]
+    pub fn with_repeat_strategy(mut self, strategy: RepeatStrategy) -> Self {
+        self.clock.strategy = strategy;
+        self
+    }
+
+    /// Set the playback direction of the sequence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::math::Vec3;
+    /// # use std::time::Duration;
+    /// # let slide_in = Tween::new(EaseMethod::Linear, Duration::from_secs(1),
+    /// #     TransformPositionLens { start: Vec3::X, end: Vec3::ZERO });
+    /// # let grow = Tween::new(EaseMethod::Linear, Duration::from_secs(1),
+    /// #     TransformScaleLens { start: Vec3::ZERO, end: Vec3::ONE });
+    /// // Shrink then slide out, undoing the slide in then grow.
+    /// let animate_out = slide_in
+    ///     .then(grow)
+    ///     .with_direction(TweeningDirection::Backward);
+    /// ```
+    ///
+    /// See [`Tweenable::set_direction()`].
+    #[must_use]
+    pub fn with_direction(mut self, direction: TweeningDirection) -> Self {
+        self.set_direction(direction);
+        self
+    }
+
+    /// Index of the current active tween in the sequence.
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index.min(self.tweens.len() - 1)
     }
 
     /// Get the current active tween in the sequence.
@@ -790,1031 +1403,3369 @@ impl<T> Sequence<T> {
     pub fn current(&self) -> &dyn Tweenable<T> {
         self.tweens[self.index()].as_ref()
     }
-}
 
-impl<T> Tweenable<T> for Sequence<T> {
-    fn duration(&self) -> Duration {
-        self.duration
+    /// Is the current iteration playing backward?
+    fn is_backward(&self) -> bool {
+        self.clock.direction(self.direction).is_backward()
     }
 
-    fn total_duration(&self) -> TotalDuration {
-        TotalDuration::Finite(self.duration)
+    /// Get the index of the child played at the given rank in the current
+    /// iteration, accounting for backward iterations playing the children in
+    /// reverse order.
+    fn play_order(&self, rank: usize) -> usize {
+        if self.is_backward() {
+            // Saturate for an empty sequence, which has no child to play
+            self.tweens.len().saturating_sub(rank + 1)
+        } else {
+            rank
+        }
     }
 
-    fn set_elapsed(&mut self, elapsed: Duration) {
-        // Set the total sequence progress
-        self.elapsed = elapsed;
-
-        // Find which tween is active in the sequence
-        let mut accum_duration = Duration::ZERO;
-        for index in 0..self.tweens.len() {
+    /// Set the elapsed time of all children for the given position inside the
+    /// current iteration.
+    ///
+    /// Children played before the active one are completed, and the ones played
+    /// after it are not started yet.
+    fn place_children(&mut self, position: Duration) {
+        if self.tweens.is_empty() {
+            return;
+        }
+        let len = self.tweens.len();
+        let mut start = Duration::ZERO;
+        let mut active = None;
+        for rank in 0..len {
+            let index = self.play_order(rank);
             let tween = &mut self.tweens[index];
-            let tween_duration = tween.duration();
-            if elapsed < accum_duration + tween_duration {
-                self.index = index;
-                let local_duration = elapsed - accum_duration;
-                tween.set_elapsed(local_duration);
-                // TODO?? set progress of other tweens after that one to 0. ??
-                return;
+            let elapsed = if active.is_some() {
+                Duration::ZERO
+            } else {
+                let span = span_duration(tween.as_ref());
+                let end = start.saturating_add(span);
+                if position < end || rank + 1 == len {
+                    active = Some(index);
+                    position - start
+                } else {
+                    start = end;
+                    span
+                }
+            };
+            tween.set_elapsed(elapsed);
+        }
+        self.index = active.unwrap();
+    }
+
+    /// Orient the children for the current iteration, then move them to the
+    /// current position.
+    fn seek_children(&mut self) {
+        let direction = self.clock.direction(self.direction);
+        orient_children(&mut self.tweens, &mut self.oriented, direction);
+        let (_, position) = self.clock.position();
+        self.place_children(position);
+        self.pending_seek = true;
+    }
+
+    /// Apply the state of the children other than the active one after a seek.
+    ///
+    /// The children are applied in the same order a continuous playback would
+    /// have last applied them, so that a tick following a seek produces the
+    /// same target state as a continuous playback. The active child is then
+    /// applied by the tick itself.
+    fn apply_seek(
+        &mut self,
+        target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut TweenEvents<'_>,
+    ) {
+        let (iteration, position) = self.clock.position();
+        let len = self.tweens.len();
+        let order: Vec<_> = (0..len).map(|rank| self.play_order(rank)).collect();
+        let active_rank = order.iter().position(|&index| index == self.index).unwrap();
+        let mut apply = |tween: &mut BoxedTweenable<T>, elapsed: Duration| {
+            tween.set_elapsed(elapsed);
+            tween.tick(Duration::ZERO, target, entity, events);
+        };
+
+        if iteration > 0 {
+            // The previous iteration left all children at their end, in the order
+            // and direction of that iteration.
+            if self.clock.strategy == RepeatStrategy::MirroredRepeat {
+                for &index in order.iter().rev() {
+                    let tween = &mut self.tweens[index];
+                    flip(tween);
+                    apply(tween, span_duration(tween.as_ref()));
+                    flip(tween);
+                }
+            } else {
+                for &index in &order {
+                    let tween = &mut self.tweens[index];
+                    apply(tween, span_duration(tween.as_ref()));
+                }
             }
-            tween.set_elapsed(tween.duration()); // ?? to prepare for next loop/rewind?
-            accum_duration += tween_duration;
+
+            // This iteration then restarted from its first child
+            apply(&mut self.tweens[order[0]], Duration::ZERO);
+        }
+
+        // This iteration completed the children played before the active one
+        for &index in &order[..active_rank] {
+            let tween = &mut self.tweens[index];
+            apply(tween, span_duration(tween.as_ref()));
         }
 
-        // None found; sequence ended
-        self.index = self.tweens.len();
+        self.place_children(position);
     }
+}
 
-    fn elapsed(&self) -> Duration {
-        self.elapsed
+impl<T> Composite<T> for Sequence<T> {
+    fn clock(&mut self) -> &mut AnimClock {
+        &mut self.clock
     }
 
-    fn tick<'a>(
+    fn tick_children(
         &mut self,
+        _position: Duration,
         mut delta: Duration,
-        target: &'a mut dyn Targetable<T>,
+        target: &mut dyn Targetable<T>,
         entity: Entity,
-        events: &mut Mut<Events<TweenCompleted>>,
-    ) -> TweenState {
-        self.elapsed = self.elapsed.saturating_add(delta).min(self.duration);
-        while self.index < self.tweens.len() {
+        events: &mut TweenEvents<'_>,
+    ) {
+        let backward = self.is_backward();
+        let len = self.tweens.len();
+        loop {
             let tween = &mut self.tweens[self.index];
-            let tween_remaining = tween.duration() - tween.elapsed();
-            if let TweenState::Active = tween.tick(delta, target, entity, events) {
-                return TweenState::Active;
+            let tween_remaining = span_duration(tween.as_ref()).saturating_sub(tween.elapsed());
+            let is_last = if backward {
+                self.index == 0
+            } else {
+                self.index + 1 == len
+            };
+            if tween.tick(delta, target, entity, events) == TweenState::Active || is_last {
+                return;
+            }
+            delta = delta.saturating_sub(tween_remaining);
+            if backward {
+                self.index -= 1;
+            } else {
+                self.index += 1;
             }
-
-            tween.rewind();
-            delta -= tween_remaining;
-            self.index += 1;
         }
-
-        TweenState::Completed
     }
 
-    fn rewind(&mut self) {
-        self.elapsed = Duration::ZERO;
-        self.index = 0;
+    fn rewind_children(&mut self) {
+        let direction = self.clock.direction(self.direction);
+        orient_children(&mut self.tweens, &mut self.oriented, direction);
+        self.index = self.play_order(0);
+        self.pending_seek = false;
         for tween in &mut self.tweens {
-            // or only first?
             tween.rewind();
         }
     }
 }
 
-/// A collection of [`Tweenable`] executing in parallel.
-pub struct Tracks<T> {
-    tracks: Vec<BoxedTweenable<T>>,
-    duration: Duration,
-    elapsed: Duration,
-}
-
-impl<T> Tracks<T> {
-    /// Create a new [`Tracks`] from an iterator over a collection of
-    /// [`Tweenable`].
-    #[must_use]
-    pub fn new(items: impl IntoIterator<Item = impl Into<BoxedTweenable<T>>>) -> Self {
-        let tracks: Vec<_> = items.into_iter().map(Into::into).collect();
-        let duration = tracks
-            .iter()
-            .map(AsRef::as_ref)
-            .map(Tweenable::duration)
-            .max()
-            .unwrap();
-        Self {
-            tracks,
-            duration,
-            elapsed: Duration::ZERO,
-        }
-    }
-}
-
-impl<T> Tweenable<T> for Tracks<T> {
+impl<T> Tweenable<T> for Sequence<T> {
     fn duration(&self) -> Duration {
-        self.duration
+        self.clock.duration
     }
 
     fn total_duration(&self) -> TotalDuration {
-        TotalDuration::Finite(self.duration)
+        composite_total_duration(&self.clock, self.tweens.iter())
     }
 
     fn set_elapsed(&mut self, elapsed: Duration) {
-        self.elapsed = elapsed;
-
-        for tweenable in &mut self.tracks {
-            tweenable.set_elapsed(elapsed);
-        }
+        // Set the total sequence progress
+        self.clock.set_elapsed(elapsed);
+        self.seek_children();
     }
 
     fn elapsed(&self) -> Duration {
-        self.elapsed
+        self.clock.elapsed()
+    }
+
+    fn set_direction(&mut self, direction: TweeningDirection) {
+        self.direction = self.clock.direction(direction);
+        self.seek_children();
     }
 
-    fn tick<'a>(
+    fn direction(&self) -> TweeningDirection {
+        self.clock.direction(self.direction)
+    }
+
+    fn tick(
         &mut self,
         delta: Duration,
-        target: &'a mut dyn Targetable<T>,
+        target: &mut dyn Targetable<T>,
         entity: Entity,
-        events: &mut Mut<Events<TweenCompleted>>,
+        events: &mut TweenEvents<'_>,
     ) -> TweenState {
-        self.elapsed = self.elapsed.saturating_add(delta).min(self.duration);
-        let mut any_active = false;
-        for tweenable in &mut self.tracks {
-            let state = tweenable.tick(delta, target, entity, events);
-            any_active = any_active || (state == TweenState::Active);
+        if self.tweens.is_empty() {
+            return TweenState::Completed;
         }
-        if any_active {
-            TweenState::Active
-        } else {
-            TweenState::Completed
+        if self.pending_seek {
+            self.pending_seek = false;
+            self.apply_seek(target, entity, events);
         }
+        tick_composite(self, delta, target, entity, events)
     }
 
     fn rewind(&mut self) {
-        self.elapsed = Duration::ZERO;
-        for tween in &mut self.tracks {
-            tween.rewind();
-        }
+        self.clock.reset();
+        self.rewind_children();
     }
 }
 
-/// A time delay that doesn't animate anything.
+/// A collection of [`Tweenable`] executing in parallel.
 ///
-/// This is generally useful for combining with other tweenables into sequences
-/// and tracks, for example to delay the start of a tween in a track relative to
-/// another track. The `menu` example (`examples/menu.rs`) uses this technique
-/// to delay the animation of its buttons.
-pub struct Delay<T> {
-    timer: Timer,
-    on_completed: Option<Box<CompletedCallback<Delay<T>>>>,
-    event_data: Option<u64>,
-}
-
-impl<T: 'static> Delay<T> {
-    /// Chain another [`Tweenable`] after this tween, making a [`Sequence`] with
-    /// the two.
-    #[must_use]
-    pub fn then(self, tween: impl Tweenable<T> + 'static) -> Sequence<T> {
-        Sequence::with_capacity(2).then(self).then(tween)
-    }
+/// Like a [`Sequence`], the tracks can be repeated as a whole with
+/// [`with_repeat_count()`](Tracks::with_repeat_count), all tracks starting
+/// again together on each iteration.
+///
+/// By default all tracks start together. Tracks can be given different start
+/// times with [`with_offsets()`](Tracks::with_offsets) or
+/// [`with_stagger()`](Tracks::with_stagger), for example to cascade the
+/// entrance of a list of items.
+///
+/// Playing the tracks backward with
+/// [`set_direction()`](Tweenable::set_direction) plays each track in the
+/// opposite of its own direction, and mirrors the timing of the tracks. So
+/// tracks shorter than the longest one start late, to complete at the time
+/// they would start when playing forward.
+pub struct Tracks<T> {
+    tracks: Vec<BoxedTweenable<T>>,
+    /// Start time of each track inside an iteration playing forward.
+    offsets: Vec<Duration>,
+    clock: AnimClock,
+    /// Direction of the first iteration.
+    direction: TweeningDirection,
+    /// Direction the tracks are currently oriented for.
+    oriented: TweeningDirection,
+    /// Set after seeking, until the state left by previous iterations is
+    /// applied on next tick.
+    pending_seek: bool,
 }
 
-impl<T> Delay<T> {
-    /// Create a new [`Delay`] with a given duration.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the duration is zero.
+impl<T> Tracks<T> {
+    /// Create a new [`Tracks`] from an iterator over a collection of
+    /// [`Tweenable`].
     #[must_use]
-    pub fn new(duration: Duration) -> Self {
-        assert!(!duration.is_zero());
+    pub fn new(items: impl IntoIterator<Item = impl Into<BoxedTweenable<T>>>) -> Self {
+        let tracks: Vec<_> = items.into_iter().map(Into::into).collect();
+        let duration = tracks
+            .iter()
+            .map(AsRef::as_ref)
+            .map(span_duration)
+            .max()
+            .unwrap();
         Self {
-            timer: Timer::new(duration, TimerMode::Once),
-            on_completed: None,
-            event_data: None,
+            offsets: vec![Duration::ZERO; tracks.len()],
+            tracks,
+            clock: AnimClock::new(duration),
+            direction: TweeningDirection::Forward,
+            oriented: TweeningDirection::Forward,
+            pending_seek: false,
         }
     }
 
-    /// Enable raising a completed event.
-    ///
-    /// If enabled, the tweenable will raise a [`TweenCompleted`] event when it
-    /// completed. This is similar to the [`set_completed()`] callback, but
-    /// uses Bevy events instead.
-    ///
-    /// # Example
+    /// Set the time at which each track starts, relative to the start of the
+    /// tracks.
     ///
-    /// ```
-    /// # use bevy_tweening::{lens::*, *};
-    /// # use bevy::{ecs::event::EventReader, math::Vec3, transform::components::Transform};
-    /// # use std::time::Duration;
-    /// let delay: Delay<Transform> = Delay::new(Duration::from_secs(5))
-    ///   .with_completed_event(42);
+    /// The duration of the tracks is the latest end time of all tracks, so
+    /// that each track plays entirely. Note that the tracks play backward in
+    /// reverse, each one ending at the mirrored time of its start.
     ///
-    /// fn my_system(mut reader: EventReader<TweenCompleted>) {
-    ///   for ev in reader.iter() {
-    ///     assert_eq!(ev.user_data, 42);
-    ///     println!("Entity {:?} raised TweenCompleted!", ev.entity);
-    ///   }
-    /// }
-    /// ```
+    /// # Panics
     ///
-    /// [`set_completed()`]: Delay::set_completed
+    /// Panics if the number of offsets differs from the number of tracks.
     #[must_use]
-    pub fn with_completed_event(mut self, user_data: u64) -> Self {
-        self.event_data = Some(user_data);
+    pub fn with_offsets(mut self, offsets: impl IntoIterator<Item = Duration>) -> Self {
+        let offsets: Vec<_> = offsets.into_iter().collect();
+        assert_eq!(offsets.len(), self.tracks.len());
+        self.offsets = offsets;
+        self.update_duration();
         self
     }
 
-    /// Set a callback invoked when the delay completes.
-    ///
-    /// The callback when invoked receives as parameters the [`Entity`] on which
-    /// the target and the animator are, as well as a reference to the
-    /// current [`Delay`]. This is similar to [`with_completed_event()`], but
-    /// with a callback instead.
+    /// Stagger the start of the tracks, each track starting the given `step`
+    /// after the previous one.
     ///
-    /// Only non-looping tweenables can complete.
+    /// This is a shortcut for [`with_offsets()`](Tracks::with_offsets) where
+    /// the track with index `i` starts at `i * step`.
     ///
     /// # Example
     ///
     /// ```
     /// # use bevy_tweening::{lens::*, *};
-    /// # use bevy::{ecs::event::EventReader, math::Vec3};
+    /// # use bevy::{math::Vec3, ui::Style};
     /// # use std::time::Duration;
-    /// let tween = Tween::new(
-    ///     // [...]
-    /// #    EaseFunction::QuadraticInOut,
-    /// #    Duration::from_secs(1),
-    /// #    TransformPositionLens {
-    /// #        start: Vec3::ZERO,
-    /// #        end: Vec3::new(3.5, 0., 0.),
-    /// #    },
-    /// )
-    /// .with_completed(|entity, delay| {
-    ///   println!("Delay of {} seconds elapsed on entity {:?}",
-    ///     delay.duration().as_secs(), entity);
-    /// });
+    /// # let make_entrance = |_| Tween::new(EaseMethod::Linear, Duration::from_secs(1),
+    /// #     TransformScaleLens { start: Vec3::ZERO, end: Vec3::ONE });
+    /// // Cascade the entrance of 5 items, each starting 100 ms after the
+    /// // previous one, for a total duration of 1.4 s.
+    /// let cascade = Tracks::new((0..5).map(make_entrance))
+    ///     .with_stagger(Duration::from_millis(100));
+    /// assert_eq!(cascade.duration(), Duration::from_millis(1400));
     /// ```
-    ///
-    /// [`with_completed_event()`]: Tween::with_completed_event
-    pub fn with_completed<C>(mut self, callback: C) -> Self
-    where
-        C: Fn(Entity, &Self) + Send + Sync + 'static,
-    {
-        self.on_completed = Some(Box::new(callback));
-        self
+    #[must_use]
+    pub fn with_stagger(self, step: Duration) -> Self {
+        let offsets: Vec<_> = (0..self.tracks.len() as u32).map(|i| step * i).collect();
+        self.with_offsets(offsets)
     }
 
-    /// Check if the delay completed.
-    pub fn is_completed(&self) -> bool {
-        self.timer.finished()
+    /// Set the number of times to repeat all the tracks.
+    #[must_use]
+    pub fn with_repeat_count(mut self, count: impl Into<RepeatCount>) -> Self {
+        self.clock.set_repeat_count(count.into());
+        self
     }
 
-    /// Get the current tweenable state.
-    pub fn state(&self) -> TweenState {
-        if self.is_completed() {
-            TweenState::Completed
-        } else {
-            TweenState::Active
-        }
+    /// Choose how the tracks behave upon a repetition.
+    #[must_use]
+    pub fn with_repeat_strategy(mut self, strategy: RepeatStrategy) -> Self {
+        self.clock.strategy = strategy;
+        self
     }
 
-    /// Set a callback invoked when the animation completes.
+    /// Set the playback direction of the tracks.
     ///
-    /// The callback when invoked receives as parameters the [`Entity`] on which
-    /// the target and the animator are, as well as a reference to the
-    /// current [`Tween`].
-    ///
-    /// Only non-looping tweenables can complete.
-    pub fn set_completed<C>(&mut self, callback: C)
-    where
-        C: Fn(Entity, &Self) + Send + Sync + 'static,
-    {
-        self.on_completed = Some(Box::new(callback));
+    /// See [`Tweenable::set_direction()`].
+    #[must_use]
+    pub fn with_direction(mut self, direction: TweeningDirection) -> Self {
+        self.set_direction(direction);
+        self
     }
 
-    /// Clear the callback invoked when the animation completes.
-    ///
-    /// See also [`set_completed()`].
-    ///
-    /// [`set_completed()`]: Tween::set_completed
-    pub fn clear_completed(&mut self) {
-        self.on_completed = None;
+    /// Create new empty tracks, to be filled with [`push()`](Tracks::push).
+    fn empty() -> Self {
+        Self {
+            tracks: vec![],
+            offsets: vec![],
+            clock: AnimClock::new(Duration::ZERO),
+            direction: TweeningDirection::Forward,
+            oriented: TweeningDirection::Forward,
+            pending_seek: false,
+        }
     }
 
-    /// Enable or disable raising a completed event.
-    ///
-    /// If enabled, the tween will raise a [`TweenCompleted`] event when the
-    /// animation completed. This is similar to the [`set_completed()`]
-    /// callback, but uses Bevy events instead.
-    ///
-    /// See [`with_completed_event()`] for details.
-    ///
-    /// [`set_completed()`]: Tween::set_completed
-    /// [`with_completed_event()`]: Tween::with_completed_event
-    pub fn set_completed_event(&mut self, user_data: u64) {
-        self.event_data = Some(user_data);
+    /// Add a track starting at the given offset.
+    fn push(&mut self, tweenable: BoxedTweenable<T>, offset: Duration) {
+        let mut tweenable = tweenable;
+        if self.oriented.is_backward() {
+            flip(&mut tweenable);
+        }
+        self.tracks.push(tweenable);
+        self.offsets.push(offset);
+        self.update_duration();
     }
 
-    /// Clear the event sent when the animation completes.
-    ///
-    /// See also [`set_completed_event()`].
-    ///
-    /// [`set_completed_event()`]: Tween::set_completed_event
-    pub fn clear_completed_event(&mut self) {
-        self.event_data = None;
+    /// Update the duration after the tracks or their offsets changed, so that
+    /// it covers the end of the latest track.
+    fn update_duration(&mut self) {
+        let duration = self
+            .tracks
+            .iter()
+            .zip(&self.offsets)
+            .map(|(track, offset)| offset.saturating_add(span_duration(track.as_ref())))
+            .max()
+            .unwrap_or_default();
+        self.clock.set_duration(duration);
+
+        // Backward, the start of the tracks depends on the duration
+        if self.clock.direction(self.direction).is_backward() {
+            self.seek_tracks();
+        }
+    }
+
+    /// Get the time inside the current iteration at which the track with the
+    /// given index starts, accounting for backward iterations mirroring the
+    /// track offsets.
+    fn track_start(&self, index: usize) -> Duration {
+        let offset = self.offsets[index];
+        if self.clock.direction(self.direction).is_backward() {
+            let end = offset.saturating_add(span_duration(self.tracks[index].as_ref()));
+            self.clock.duration.saturating_sub(end)
+        } else {
+            offset
+        }
+    }
+
+    /// Set the elapsed time of all tracks for the given position inside the
+    /// current iteration.
+    fn place_tracks(&mut self, position: Duration) {
+        for index in 0..self.tracks.len() {
+            let start = self.track_start(index);
+            let tweenable = &mut self.tracks[index];
+            let elapsed = position
+                .saturating_sub(start)
+                .min(span_duration(tweenable.as_ref()));
+            tweenable.set_elapsed(elapsed);
+        }
+    }
+
+    /// Orient the tracks for the current iteration, then move them to the
+    /// current position.
+    fn seek_tracks(&mut self) {
+        let direction = self.clock.direction(self.direction);
+        orient_children(&mut self.tracks, &mut self.oriented, direction);
+        let (_, position) = self.clock.position();
+        self.place_tracks(position);
+        self.pending_seek = true;
+    }
+
+    /// Apply the state left on the target by the previous iterations after a
+    /// seek, like [`Sequence`] does, as well as the state of the tracks which
+    /// already started in this iteration.
+    fn apply_seek(
+        &mut self,
+        target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut TweenEvents<'_>,
+    ) {
+        let (iteration, position) = self.clock.position();
+        if iteration > 0 {
+            // The previous iteration left all tracks at their end, in the
+            // direction of that iteration.
+            let mirrored = self.clock.strategy == RepeatStrategy::MirroredRepeat;
+            for tweenable in &mut self.tracks {
+                if mirrored {
+                    flip(tweenable);
+                }
+                tweenable.set_elapsed(span_duration(tweenable.as_ref()));
+                tweenable.tick(Duration::ZERO, target, entity, events);
+                if mirrored {
+                    flip(tweenable);
+                }
+            }
+        }
+        self.place_tracks(position);
+        self.tick_children(position, Duration::ZERO, target, entity, events);
     }
 }
 
-impl<T> Tweenable<T> for Delay<T> {
+impl<T> Composite<T> for Tracks<T> {
+    fn clock(&mut self) -> &mut AnimClock {
+        &mut self.clock
+    }
+
+    fn tick_children(
+        &mut self,
+        position: Duration,
+        delta: Duration,
+        target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut TweenEvents<'_>,
+    ) {
+        let end = position.saturating_add(delta);
+        for index in 0..self.tracks.len() {
+            let start = self.track_start(index);
+            let tweenable = &mut self.tracks[index];
+            if position >= start {
+                tweenable.tick(delta, target, entity, events);
+            } else if end >= start {
+                tweenable.tick(end - start, target, entity, events);
+            }
+        }
+    }
+
+    fn rewind_children(&mut self) {
+        let direction = self.clock.direction(self.direction);
+        orient_children(&mut self.tracks, &mut self.oriented, direction);
+        self.pending_seek = false;
+        for tween in &mut self.tracks {
+            tween.rewind();
+        }
+    }
+}
+
+impl<T> Tweenable<T> for Tracks<T> {
     fn duration(&self) -> Duration {
-        self.timer.duration()
+        self.clock.duration
     }
 
     fn total_duration(&self) -> TotalDuration {
-        TotalDuration::Finite(self.duration())
+        composite_total_duration(&self.clock, self.tracks.iter())
     }
 
     fn set_elapsed(&mut self, elapsed: Duration) {
-        // need to reset() to clear finished() unfortunately
-        self.timer.reset();
-        self.timer.set_elapsed(elapsed);
-        // set_elapsed() does not update finished() etc. which we rely on
-        self.timer.tick(Duration::ZERO);
+        self.clock.set_elapsed(elapsed);
+        self.seek_tracks();
     }
 
     fn elapsed(&self) -> Duration {
-        self.timer.elapsed()
+        self.clock.elapsed()
+    }
+
+    fn set_direction(&mut self, direction: TweeningDirection) {
+        self.direction = self.clock.direction(direction);
+        self.seek_tracks();
+    }
+
+    fn direction(&self) -> TweeningDirection {
+        self.clock.direction(self.direction)
     }
 
-    fn tick<'a>(
+    fn tick(
         &mut self,
         delta: Duration,
-        _target: &'a mut dyn Targetable<T>,
+        target: &mut dyn Targetable<T>,
         entity: Entity,
-        events: &mut Mut<Events<TweenCompleted>>,
+        events: &mut TweenEvents<'_>,
     ) -> TweenState {
-        let was_completed = self.is_completed();
+        if self.pending_seek {
+            self.pending_seek = false;
+            self.apply_seek(target, entity, events);
+        }
+        tick_composite(self, delta, target, entity, events)
+    }
 
-        self.timer.tick(delta);
+    fn rewind(&mut self) {
+        self.clock.reset();
+        self.rewind_children();
+    }
+}
 
-        let state = self.state();
+/// Position of a child or a label inside a [`Timeline`].
+///
+/// Positions relative to the previous child refer to the child added last to
+/// the timeline, or to the start of the timeline if no child was added yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimelinePosition {
+    /// At the given time from the start of the timeline.
+    At(Duration),
+    /// At the end of the timeline, after all the children added so far.
+    End,
+    /// The given time after the end of the previous child, leaving a gap.
+    AfterPrevious(Duration),
+    /// The given time before the end of the previous child, overlapping it.
+    /// This is clamped to the start of the timeline.
+    BeforePreviousEnd(Duration),
+    /// The given time after the start of the previous child.
+    WithPrevious(Duration),
+    /// At the time of the label with the given name.
+    Label(String),
+}
 
-        // If completed this frame, notify the user
-        if (state == TweenState::Completed) && !was_completed {
-            if let Some(user_data) = &self.event_data {
-                events.send(TweenCompleted {
-                    entity,
-                    user_data: *user_data,
-                });
-            }
-            if let Some(cb) = &self.on_completed {
-                cb(entity, self);
-            }
-        }
+impl From<Duration> for TimelinePosition {
+    fn from(time: Duration) -> Self {
+        Self::At(time)
+    }
+}
 
-        state
+impl From<&str> for TimelinePosition {
+    fn from(label: &str) -> Self {
+        Self::Label(label.to_string())
     }
+}
 
-    fn rewind(&mut self) {
-        self.timer.reset();
+impl From<String> for TimelinePosition {
+    fn from(label: String) -> Self {
+        Self::Label(label)
     }
 }
 
-/// A dummy tweenable that does nothing.
+/// A collection of [`Tweenable`] placed at arbitrary times.
 ///
-/// This is useful for adding an Animator<T> component
-pub struct Dummy<T: Component> {
-    _marker: std::marker::PhantomData<T>,
+/// Unlike a [`Sequence`] or [`Tracks`], which respectively play their children
+/// strictly one after the other or strictly together, the children of a
+/// timeline can be placed at any time, either absolute or relative to the
+/// previous child, leaving gaps or overlapping. Named labels mark times of
+/// interest, which children can be placed at, and which the timeline can seek
+/// to with [`seek_to_label()`](Timeline::seek_to_label).
+///
+/// Overlapping children animating the same fields all apply their state to
+/// the target, in the order they were added to the timeline, so the child
+/// added last takes precedence while active.
+///
+/// Like [`Tracks`], the timeline can be repeated with
+/// [`with_repeat_count()`](Timeline::with_repeat_count) and played backward
+/// with [`set_direction()`](Tweenable::set_direction), which mirrors the time
+/// of all children and labels.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_tweening::{lens::*, *};
+/// # use bevy::math::Vec3;
+/// # use std::time::Duration;
+/// # let jump = Tween::new(EaseMethod::Linear, Duration::from_secs(1),
+/// #     TransformPositionLens { start: Vec3::ZERO, end: Vec3::Y });
+/// # let fall = Tween::new(EaseMethod::Linear, Duration::from_secs(1),
+/// #     TransformPositionLens { start: Vec3::Y, end: Vec3::ZERO });
+/// # let squash = Tween::new(EaseMethod::Linear, Duration::from_millis(300),
+/// #     TransformScaleLens { start: Vec3::ONE, end: Vec3::new(1.2, 0.8, 1.) });
+/// let mut timeline = Timeline::new()
+///     .then(jump)
+///     .then(fall)
+///     .with_label("impact", TimelinePosition::End)
+///     // Start squashing slightly before the impact
+///     .add(squash, TimelinePosition::BeforePreviousEnd(Duration::from_millis(100)));
+/// assert_eq!(timeline.duration(), Duration::from_millis(2200));
+///
+/// // Jump straight to the impact
+/// timeline.seek_to_label("impact");
+/// assert_eq!(timeline.elapsed(), Duration::from_secs(2));
+/// ```
+pub struct Timeline<T> {
+    tracks: Tracks<T>,
+    labels: HashMap<String, Duration>,
+    /// Start and end time of the child added last.
+    previous: (Duration, Duration),
 }
 
-impl<T: Component> Dummy<T> {
-    /// Creates a dummy tweenable that does nothing.
-    ///
-    /// *Technically it returns a Delay<T> with a duration of 1ns
-    // #[allow(warnings)]
-    #[must_use]
-    pub fn new() -> Delay<T> {
-        Delay::<T>::new(Duration::from_nanos(1))
+impl<T> Default for Timeline<T> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use std::{
-        sync::{Arc, Mutex},
-        time::Duration,
-    };
+impl<T> Timeline<T> {
+    /// Create a new empty timeline.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            tracks: Tracks::empty(),
+            labels: HashMap::default(),
+            previous: (Duration::ZERO, Duration::ZERO),
+        }
+    }
 
-    use bevy::ecs::{event::Events, system::SystemState};
+    /// Add a [`Tweenable`] at the given position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position refers to a label which doesn't exist.
+    #[must_use]
+    pub fn add(
+        mut self,
+        tween: impl Tweenable<T> + 'static,
+        position: impl Into<TimelinePosition>,
+    ) -> Self {
+        let start = self.resolve(&position.into());
+        let end = start.saturating_add(span_duration(&tween));
+        self.tracks.push(Box::new(tween), start);
+        self.previous = (start, end);
+        self
+    }
 
-    use super::*;
-    use crate::{lens::*, test_utils::*};
+    /// Append a [`Tweenable`] at the end of this timeline, like
+    /// [`Sequence::then()`] does.
+    #[must_use]
+    pub fn then(self, tween: impl Tweenable<T> + 'static) -> Self {
+        self.add(tween, TimelinePosition::End)
+    }
 
-    #[derive(Default, Copy, Clone)]
-    struct CallbackMonitor {
-        invoke_count: u64,
-        last_reported_count: u32,
+    /// Add a label with the given name at the given position.
+    ///
+    /// Adding a label doesn't change the previous child which relative
+    /// positions refer to, nor the duration of the timeline. Adding a label
+    /// with the same name as an existing one replaces it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position refers to a label which doesn't exist.
+    #[must_use]
+    pub fn with_label(
+        mut self,
+        label: impl Into<String>,
+        position: impl Into<TimelinePosition>,
+    ) -> Self {
+        let time = self.resolve(&position.into());
+        self.labels.insert(label.into(), time);
+        self
     }
 
-    /// Utility to create a tween for testing.
-    fn make_test_tween() -> Tween<Transform> {
-        Tween::new(
-            EaseMethod::Linear,
-            Duration::from_secs(1),
-            TransformPositionLens {
-                start: Vec3::ZERO,
-                end: Vec3::ONE,
-            },
-        )
+    /// Set the number of times to repeat the entire timeline.
+    #[must_use]
+    pub fn with_repeat_count(mut self, count: impl Into<RepeatCount>) -> Self {
+        self.tracks = self.tracks.with_repeat_count(count);
+        self
     }
 
-    /// Utility to create a test environment to tick a tween.
-    fn make_test_env() -> (World, Entity) {
-        let mut world = World::new();
-        world.init_resource::<Events<TweenCompleted>>();
-        let entity = world.spawn(Transform::default()).id();
-        (world, entity)
+    /// Choose how the timeline behaves upon a repetition.
+    #[must_use]
+    pub fn with_repeat_strategy(mut self, strategy: RepeatStrategy) -> Self {
+        self.tracks = self.tracks.with_repeat_strategy(strategy);
+        self
     }
 
-    /// Manually tick a test tweenable targeting a component.
-    fn manual_tick_component<T: Component>(
-        duration: Duration,
-        tween: &mut dyn Tweenable<T>,
-        world: &mut World,
-        entity: Entity,
-    ) -> TweenState {
-        world.resource_scope(
-            |world: &mut World, mut events: Mut<Events<TweenCompleted>>| {
-                let transform = world.get_mut::<T>(entity).unwrap();
-                let mut target = ComponentTarget::new(transform);
-                tween.tick(duration, &mut target, entity, &mut events)
-            },
-        )
+    /// Set the playback direction of the timeline.
+    ///
+    /// See [`Tweenable::set_direction()`].
+    #[must_use]
+    pub fn with_direction(mut self, direction: TweeningDirection) -> Self {
+        self.set_direction(direction);
+        self
     }
 
-    #[test]
-    fn anim_clock_precision() {
-        let duration = Duration::from_millis(1);
-        let mut clock = AnimClock::new(duration);
-        clock.total_duration = TotalDuration::Infinite;
+    /// Get the time of the label with the given name, relative to the start of
+    /// the timeline, if any.
+    #[must_use]
+    pub fn label(&self, label: &str) -> Option<Duration> {
+        self.labels.get(label).copied()
+    }
 
-        let test_ticks = [
-            Duration::from_micros(123),
-            Duration::from_millis(1),
-            Duration::from_secs_f32(1. / 24.),
-            Duration::from_secs_f32(1. / 30.),
-            Duration::from_secs_f32(1. / 60.),
-            Duration::from_secs_f32(1. / 120.),
-            Duration::from_secs_f32(1. / 144.),
-            Duration::from_secs_f32(1. / 240.),
-        ];
+    /// Seek the timeline to the label with the given name, inside the current
+    /// iteration.
+    ///
+    /// Like [`set_elapsed()`](Tweenable::set_elapsed), this doesn't apply the
+    /// change to the target until the next tick. Returns `false`, leaving the
+    /// timeline unchanged, if no label with that name exists.
+    pub fn seek_to_label(&mut self, label: &str) -> bool {
+        let time = match self.label(label) {
+            Some(time) => time,
+            None => return false,
+        };
+        let clock = &self.tracks.clock;
+        let (iteration, _) = clock.position();
+        let time = time.min(clock.duration);
+        let position = if self.direction().is_backward() {
+            clock.duration - time
+        } else {
+            time
+        };
+        let elapsed = clock.duration.saturating_mul(iteration) + position;
+        self.set_elapsed(elapsed);
+        true
+    }
 
-        let mut times_completed = 0;
-        let mut total_duration = Duration::ZERO;
-        for i in 0..10_000_000 {
-            let tick = test_ticks[i % test_ticks.len()];
-            times_completed += clock.tick(tick).1;
-            total_duration += tick;
+    /// Resolve a position into a time relative to the start of the timeline.
+    fn resolve(&self, position: &TimelinePosition) -> Duration {
+        let (previous_start, previous_end) = self.previous;
+        match position {
+            TimelinePosition::At(time) => *time,
+            TimelinePosition::End => self.tracks.clock.duration,
+            TimelinePosition::AfterPrevious(gap) => previous_end.saturating_add(*gap),
+            TimelinePosition::BeforePreviousEnd(overlap) => previous_end.saturating_sub(*overlap),
+            TimelinePosition::WithPrevious(offset) => previous_start.saturating_add(*offset),
+            TimelinePosition::Label(label) => self
+                .label(label)
+                .unwrap_or_else(|| panic!("Unknown timeline label '{}'", label)),
         }
+    }
+}
 
-        assert_eq!(
-            (total_duration.as_secs_f64() / duration.as_secs_f64()) as i32,
-            times_completed
-        );
+impl<T> Tweenable<T> for Timeline<T> {
+    fn duration(&self) -> Duration {
+        self.tracks.duration()
     }
 
-    #[test]
-    fn into_repeat_count() {
-        let tween = Tween::new(
-            EaseMethod::Linear,
-            Duration::from_secs(1),
-            TransformPositionLens {
+    fn total_duration(&self) -> TotalDuration {
+        self.tracks.total_duration()
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.tracks.set_elapsed(elapsed);
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.tracks.elapsed()
+    }
+
+    fn set_direction(&mut self, direction: TweeningDirection) {
+        self.tracks.set_direction(direction);
+    }
+
+    fn direction(&self) -> TweeningDirection {
+        self.tracks.direction()
+    }
+
+    fn tick(
+        &mut self,
+        delta: Duration,
+        target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut TweenEvents<'_>,
+    ) -> TweenState {
+        if self.tracks.tracks.is_empty() {
+            return TweenState::Completed;
+        }
+        self.tracks.tick(delta, target, entity, events)
+    }
+
+    fn rewind(&mut self) {
+        self.tracks.rewind();
+    }
+}
+
+/// Type of a callback invoked when a [`Marker`] is reached.
+///
+/// See [`Marker::with_callback()`] for usage.
+pub type MarkerCallback = dyn Fn(Entity, &Marker) + Send + Sync + 'static;
+
+/// Position of a [`Marker`] inside a single iteration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MarkerPosition {
+    Progress(f32),
+    Time(Duration),
+}
+
+/// A point of interest inside the iterations of a [`Marked`] tweenable.
+///
+/// When the tweenable reaches the marker, the marker raises a
+/// [`MarkerReached`] event with its own user data if enabled with
+/// [`with_event()`](Marker::with_event), raises an event of a custom type if
+/// enabled with [`with_payload()`](Marker::with_payload), and invokes its
+/// callback if any.
+pub struct Marker {
+    position: MarkerPosition,
+    user_data: Option<u64>,
+    payload: Option<Box<PayloadSender>>,
+    callback: Option<Box<MarkerCallback>>,
+    deferred_callback: Option<Arc<DeferredCallback>>,
+}
+
+impl Marker {
+    /// Create a marker at the given progress ratio of each iteration.
+    ///
+    /// The progress is clamped to \[0:1\].
+    #[must_use]
+    pub fn at_progress(progress: f32) -> Self {
+        Self {
+            position: MarkerPosition::Progress(progress.clamp(0., 1.)),
+            user_data: None,
+            payload: None,
+            callback: None,
+            deferred_callback: None,
+        }
+    }
+
+    /// Create a marker at the given time from the start of each iteration.
+    ///
+    /// The time is clamped to the duration of a single iteration.
+    #[must_use]
+    pub fn at_time(time: Duration) -> Self {
+        Self {
+            position: MarkerPosition::Time(time),
+            user_data: None,
+            payload: None,
+            callback: None,
+            deferred_callback: None,
+        }
+    }
+
+    /// Enable raising a [`MarkerReached`] event with the given user data when
+    /// the marker is reached.
+    #[must_use]
+    pub fn with_event(mut self, user_data: u64) -> Self {
+        self.user_data = Some(user_data);
+        self
+    }
+
+    /// Enable raising a clone of the given event of a custom type when the
+    /// marker is reached.
+    ///
+    /// See [`Tween::with_completed_payload()`] for details.
+    #[must_use]
+    pub fn with_payload<E: Event + Clone>(mut self, event: E) -> Self {
+        self.payload = Some(payload_sender(event));
+        self
+    }
+
+    /// Set a callback invoked when the marker is reached.
+    ///
+    /// The callback when invoked receives as parameters the [`Entity`] on which
+    /// the target and the animator are, as well as a reference to the marker.
+    #[must_use]
+    pub fn with_callback<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(Entity, &Self) + Send + Sync + 'static,
+    {
+        self.callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Set a deferred callback invoked with a mutable access to the [`World`]
+    /// when the marker is reached.
+    ///
+    /// See [`Tween::with_completed_deferred()`] for details.
+    #[must_use]
+    pub fn with_deferred_callback<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(Entity, &mut World) + Send + Sync + 'static,
+    {
+        self.deferred_callback = Some(Arc::new(callback));
+        self
+    }
+
+    /// Get the user data of the event raised by this marker, if enabled.
+    #[must_use]
+    pub fn user_data(&self) -> Option<u64> {
+        self.user_data
+    }
+
+    /// Get the time of the marker from the start of an iteration of the given
+    /// duration.
+    fn time(&self, duration: Duration) -> Duration {
+        match self.position {
+            MarkerPosition::Progress(progress) => duration.mul_f32(progress),
+            MarkerPosition::Time(time) => time.min(duration),
+        }
+    }
+
+    fn notify(&self, entity: Entity, events: &mut TweenEvents<'_>) {
+        if let Some(user_data) = self.user_data {
+            events.send_payload(MarkerReached { entity, user_data });
+        }
+        if let Some(payload) = &self.payload {
+            payload(events);
+        }
+        if let Some(cb) = &self.callback {
+            cb(entity, self);
+        }
+        if let Some(cb) = &self.deferred_callback {
+            events.defer_callback(cb, entity);
+        }
+    }
+}
+
+/// A [`Tweenable`] with some [`Marker`] attached to it.
+///
+/// This wraps any tweenable to raise events or invoke callbacks at arbitrary
+/// points of its iterations, in addition to the ones it raises itself when
+/// completing. The markers are placed relative to the elapsed time of each
+/// iteration, whatever the playback direction, and are reached again on each
+/// iteration when repeating.
+///
+/// All the markers reached during a single tick are notified in order, even
+/// when the tick skips past several of them at once, after any event the
+/// wrapped tweenable raised itself. Like [`TweenCompleted`] events, no marker
+/// is notified while ticking backward.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_tweening::{lens::*, *};
+/// # use bevy::math::Vec3;
+/// # use std::time::Duration;
+/// # const JUMP_PEAK: u64 = 1;
+/// # let jump = Tween::new(EaseFunction::QuadraticOut, Duration::from_secs(1),
+/// #     TransformPositionLens { start: Vec3::ZERO, end: Vec3::Y });
+/// // Raise an event when the jump peaks, half way through
+/// let jump = Marked::new(jump).with_marker(Marker::at_progress(0.5).with_event(JUMP_PEAK));
+/// ```
+pub struct Marked<T> {
+    tweenable: BoxedTweenable<T>,
+    markers: Vec<Marker>,
+}
+
+impl<T> Marked<T> {
+    /// Wrap a tweenable to attach markers to it.
+    #[must_use]
+    pub fn new(tweenable: impl Into<BoxedTweenable<T>>) -> Self {
+        Self {
+            tweenable: tweenable.into(),
+            markers: vec![],
+        }
+    }
+
+    /// Add a marker.
+    #[must_use]
+    pub fn with_marker(mut self, marker: Marker) -> Self {
+        self.add_marker(marker);
+        self
+    }
+
+    /// Add a marker.
+    pub fn add_marker(&mut self, marker: Marker) {
+        self.markers.push(marker);
+    }
+
+    /// Remove all the markers.
+    pub fn clear_markers(&mut self) {
+        self.markers.clear();
+    }
+
+    /// Get the wrapped tweenable.
+    #[must_use]
+    pub fn tweenable(&self) -> &dyn Tweenable<T> {
+        self.tweenable.as_ref()
+    }
+
+    /// Notify all the markers reached after `from` and up to `to`, in order.
+    fn notify_markers(
+        &self,
+        from: Duration,
+        to: Duration,
+        entity: Entity,
+        events: &mut TweenEvents<'_>,
+    ) {
+        let duration = self.tweenable.duration();
+        if duration.is_zero() {
+            return;
+        }
+        let mut times: Vec<_> = self
+            .markers
+            .iter()
+            .map(|marker| (marker.time(duration), marker))
+            .collect();
+        times.sort_by_key(|(time, _)| *time);
+
+        let total = match self.tweenable.total_duration() {
+            TotalDuration::Finite(total) => total,
+            TotalDuration::Infinite => Duration::MAX,
+        };
+        let first = (from.as_nanos() / duration.as_nanos()) as u32;
+        let last = (to.as_nanos() / duration.as_nanos()) as u32;
+        for iteration in first..=last {
+            let start = duration * iteration;
+            // Once completed, no new iteration starts
+            if start >= total {
+                break;
+            }
+            for (time, marker) in &times {
+                let time = start + *time;
+                // A marker at the very start is reached by the first tick
+                if (time > from || from.is_zero()) && time <= to {
+                    marker.notify(entity, events);
+                }
+            }
+        }
+    }
+}
+
+impl<T> Tweenable<T> for Marked<T> {
+    fn duration(&self) -> Duration {
+        self.tweenable.duration()
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        self.tweenable.total_duration()
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.tweenable.set_elapsed(elapsed);
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.tweenable.elapsed()
+    }
+
+    fn set_direction(&mut self, direction: TweeningDirection) {
+        self.tweenable.set_direction(direction);
+    }
+
+    fn direction(&self) -> TweeningDirection {
+        self.tweenable.direction()
+    }
+
+    fn tick(
+        &mut self,
+        delta: Duration,
+        target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut TweenEvents<'_>,
+    ) -> TweenState {
+        let from = self.tweenable.elapsed();
+        let state = self.tweenable.tick(delta, target, entity, events);
+        let to = self.tweenable.elapsed();
+        if to > from {
+            self.notify_markers(from, to, entity, events);
+        }
+        state
+    }
+
+    fn tick_backward(
+        &mut self,
+        delta: Duration,
+        target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut TweenEvents<'_>,
+    ) -> TweenState {
+        self.tweenable.tick_backward(delta, target, entity, events)
+    }
+
+    fn rewind(&mut self) {
+        self.tweenable.rewind();
+    }
+}
+
+/// A time delay that doesn't animate anything.
+///
+/// This is generally useful for combining with other tweenables into sequences
+/// and tracks, for example to delay the start of a tween in a track relative to
+/// another track. The `menu` example (`examples/menu.rs`) uses this technique
+/// to delay the animation of its buttons.
+pub struct Delay<T> {
+    timer: Timer,
+    direction: TweeningDirection,
+    on_completed: Option<Box<CompletedCallback<Delay<T>>>>,
+    on_completed_deferred: Option<Arc<DeferredCallback>>,
+    event_data: Option<u64>,
+    payload: Option<Box<PayloadSender>>,
+}
+
+impl<T: 'static> Delay<T> {
+    /// Chain another [`Tweenable`] after this tween, making a [`Sequence`] with
+    /// the two.
+    #[must_use]
+    pub fn then(self, tween: impl Tweenable<T> + 'static) -> Sequence<T> {
+        Sequence::with_capacity(2).then(self).then(tween)
+    }
+}
+
+impl<T> Delay<T> {
+    /// Create a new [`Delay`] with a given duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the duration is zero.
+    #[must_use]
+    pub fn new(duration: Duration) -> Self {
+        assert!(!duration.is_zero());
+        Self {
+            timer: Timer::new(duration, TimerMode::Once),
+            direction: TweeningDirection::Forward,
+            on_completed: None,
+            on_completed_deferred: None,
+            event_data: None,
+            payload: None,
+        }
+    }
+
+    /// Enable raising a completed event.
+    ///
+    /// If enabled, the tweenable will raise a [`TweenCompleted`] event when it
+    /// completed. This is similar to the [`set_completed()`] callback, but
+    /// uses Bevy events instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::{ecs::event::EventReader, math::Vec3, transform::components::Transform};
+    /// # use std::time::Duration;
+    /// let delay: Delay<Transform> = Delay::new(Duration::from_secs(5))
+    ///   .with_completed_event(42);
+    ///
+    /// fn my_system(mut reader: EventReader<TweenCompleted>) {
+    ///   for ev in reader.iter() {
+    ///     assert_eq!(ev.user_data, 42);
+    ///     println!("Entity {:?} raised TweenCompleted!", ev.entity);
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// [`set_completed()`]: Delay::set_completed
+    #[must_use]
+    pub fn with_completed_event(mut self, user_data: u64) -> Self {
+        self.event_data = Some(user_data);
+        self
+    }
+
+    /// Set a callback invoked when the delay completes.
+    ///
+    /// The callback when invoked receives as parameters the [`Entity`] on which
+    /// the target and the animator are, as well as a reference to the
+    /// current [`Delay`]. This is similar to [`with_completed_event()`], but
+    /// with a callback instead.
+    ///
+    /// Only non-looping tweenables can complete.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::{ecs::event::EventReader, math::Vec3};
+    /// # use std::time::Duration;
+    /// let tween = Tween::new(
+    ///     // [...]
+    /// #    EaseFunction::QuadraticInOut,
+    /// #    Duration::from_secs(1),
+    /// #    TransformPositionLens {
+    /// #        start: Vec3::ZERO,
+    /// #        end: Vec3::new(3.5, 0., 0.),
+    /// #    },
+    /// )
+    /// .with_completed(|entity, delay| {
+    ///   println!("Delay of {} seconds elapsed on entity {:?}",
+    ///     delay.duration().as_secs(), entity);
+    /// });
+    /// ```
+    ///
+    /// [`with_completed_event()`]: Tween::with_completed_event
+    pub fn with_completed<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(Entity, &Self) + Send + Sync + 'static,
+    {
+        self.on_completed = Some(Box::new(callback));
+        self
+    }
+
+    /// Check if the delay completed.
+    pub fn is_completed(&self) -> bool {
+        self.timer.finished()
+    }
+
+    /// Get the current tweenable state.
+    pub fn state(&self) -> TweenState {
+        if self.is_completed() {
+            TweenState::Completed
+        } else {
+            TweenState::Active
+        }
+    }
+
+    /// Set a callback invoked when the animation completes.
+    ///
+    /// The callback when invoked receives as parameters the [`Entity`] on which
+    /// the target and the animator are, as well as a reference to the
+    /// current [`Tween`].
+    ///
+    /// Only non-looping tweenables can complete.
+    pub fn set_completed<C>(&mut self, callback: C)
+    where
+        C: Fn(Entity, &Self) + Send + Sync + 'static,
+    {
+        self.on_completed = Some(Box::new(callback));
+    }
+
+    /// Clear the callback invoked when the animation completes.
+    ///
+    /// See also [`set_completed()`].
+    ///
+    /// [`set_completed()`]: Tween::set_completed
+    pub fn clear_completed(&mut self) {
+        self.on_completed = None;
+    }
+
+    /// Set a deferred callback invoked when the delay completes.
+    ///
+    /// Unlike [`with_completed()`], the callback receives a mutable access to
+    /// the [`World`], allowing it for example to despawn the entity, insert
+    /// some components, or start a new animation. To allow this, the callback
+    /// is not invoked immediately while ticking, but once the animator systems
+    /// ran, like [`Commands`] are.
+    ///
+    /// Only non-looping tweenables can complete.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::{math::Vec3, prelude::*};
+    /// # use std::time::Duration;
+    /// #[derive(Component)]
+    /// struct Opened;
+    ///
+    /// let tween = Tween::new(
+    ///     // [...]
+    /// #    EaseFunction::QuadraticInOut,
+    /// #    Duration::from_secs(1),
+    /// #    TransformPositionLens {
+    /// #        start: Vec3::ZERO,
+    /// #        end: Vec3::new(3.5, 0., 0.),
+    /// #    },
+    /// )
+    /// .with_completed_deferred(|entity, world| {
+    ///   world.entity_mut(entity).insert(Opened);
+    /// });
+    /// ```
+    ///
+    /// [`with_completed()`]: Delay::with_completed
+    #[must_use]
+    pub fn with_completed_deferred<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(Entity, &mut World) + Send + Sync + 'static,
+    {
+        self.on_completed_deferred = Some(Arc::new(callback));
+        self
+    }
+
+    /// Set a deferred callback invoked when the delay completes.
+    ///
+    /// See [`with_completed_deferred()`] for details.
+    ///
+    /// [`with_completed_deferred()`]: Delay::with_completed_deferred
+    pub fn set_completed_deferred<C>(&mut self, callback: C)
+    where
+        C: Fn(Entity, &mut World) + Send + Sync + 'static,
+    {
+        self.on_completed_deferred = Some(Arc::new(callback));
+    }
+
+    /// Clear the deferred callback invoked when the delay completes.
+    ///
+    /// See also [`set_completed_deferred()`].
+    ///
+    /// [`set_completed_deferred()`]: Delay::set_completed_deferred
+    pub fn clear_completed_deferred(&mut self) {
+        self.on_completed_deferred = None;
+    }
+
+    /// Enable or disable raising a completed event.
+    ///
+    /// If enabled, the tween will raise a [`TweenCompleted`] event when the
+    /// animation completed. This is similar to the [`set_completed()`]
+    /// callback, but uses Bevy events instead.
+    ///
+    /// See [`with_completed_event()`] for details.
+    ///
+    /// [`set_completed()`]: Tween::set_completed
+    /// [`with_completed_event()`]: Tween::with_completed_event
+    pub fn set_completed_event(&mut self, user_data: u64) {
+        self.event_data = Some(user_data);
+    }
+
+    /// Clear the event sent when the animation completes.
+    ///
+    /// See also [`set_completed_event()`].
+    ///
+    /// [`set_completed_event()`]: Tween::set_completed_event
+    pub fn clear_completed_event(&mut self) {
+        self.event_data = None;
+    }
+
+    /// Enable raising a completed event of a custom type.
+    ///
+    /// If enabled, the delay will raise a clone of the given event each time it
+    /// completes, in addition to any [`TweenCompleted`] event enabled with
+    /// [`with_completed_event()`]. Unlike the opaque user data of
+    /// [`TweenCompleted`], the event can hold any meaningful payload. The type
+    /// of the event must be registered with
+    /// [`TweenEventPlugin`](crate::TweenEventPlugin). See [`TweenEvents`] for
+    /// details about when the event is raised.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::{ecs::event::EventReader, transform::components::Transform};
+    /// # use std::time::Duration;
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// enum DoorEvent {
+    ///     Opened,
+    ///     Closed,
+    /// }
+    ///
+    /// let delay: Delay<Transform> = Delay::new(Duration::from_secs(5))
+    ///   .with_completed_payload(DoorEvent::Opened);
+    ///
+    /// fn my_system(mut reader: EventReader<DoorEvent>) {
+    ///   for ev in reader.iter() {
+    ///     assert_eq!(*ev, DoorEvent::Opened);
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// [`with_completed_event()`]: Delay::with_completed_event
+    #[must_use]
+    pub fn with_completed_payload<E: Event + Clone>(mut self, event: E) -> Self {
+        self.payload = Some(payload_sender(event));
+        self
+    }
+
+    /// Enable raising a completed event of a custom type.
+    ///
+    /// See [`with_completed_payload()`] for details.
+    ///
+    /// [`with_completed_payload()`]: Delay::with_completed_payload
+    pub fn set_completed_payload<E: Event + Clone>(&mut self, event: E) {
+        self.payload = Some(payload_sender(event));
+    }
+
+    /// Clear the custom event sent when the delay completes.
+    ///
+    /// See also [`set_completed_payload()`].
+    ///
+    /// [`set_completed_payload()`]: Delay::set_completed_payload
+    pub fn clear_completed_payload(&mut self) {
+        self.payload = None;
+    }
+}
+
+impl<T> Tweenable<T> for Delay<T> {
+    fn duration(&self) -> Duration {
+        self.timer.duration()
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        TotalDuration::Finite(self.duration())
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        // need to reset() to clear finished() unfortunately
+        self.timer.reset();
+        self.timer.set_elapsed(elapsed);
+        // set_elapsed() does not update finished() etc. which we rely on
+        self.timer.tick(Duration::ZERO);
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.timer.elapsed()
+    }
+
+    fn set_direction(&mut self, direction: TweeningDirection) {
+        // A delay plays the same in both directions
+        self.direction = direction;
+    }
+
+    fn direction(&self) -> TweeningDirection {
+        self.direction
+    }
+
+    fn tick(
+        &mut self,
+        delta: Duration,
+        _target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut TweenEvents<'_>,
+    ) -> TweenState {
+        let was_completed = self.is_completed();
+
+        self.timer.tick(delta);
+
+        let state = self.state();
+
+        // If completed this frame, notify the user
+        if (state == TweenState::Completed) && !was_completed {
+            if let Some(user_data) = &self.event_data {
+                events.send(TweenCompleted {
+                    entity,
+                    user_data: *user_data,
+                });
+            }
+            if let Some(payload) = &self.payload {
+                payload(events);
+            }
+            if let Some(cb) = &self.on_completed {
+                cb(entity, self);
+            }
+            if let Some(cb) = &self.on_completed_deferred {
+                events.defer_callback(cb, entity);
+            }
+        }
+
+        state
+    }
+
+    fn rewind(&mut self) {
+        self.timer.reset();
+    }
+}
+
+/// A dummy tweenable that does nothing.
+///
+/// This is useful for adding an Animator<T> component
+pub struct Dummy<T: Component> {
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T: Component> Dummy<T> {
+    /// Creates a dummy tweenable that does nothing.
+    ///
+    /// *Technically it returns a Delay<T> with a duration of 1ns
+    #[allow(clippy::new_ret_no_self)]
+    #[must_use]
+    pub fn new() -> Delay<T> {
+        Delay::<T>::new(Duration::from_nanos(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use bevy::ecs::{event::Events, system::SystemState};
+
+    use super::*;
+    use crate::{lens::*, test_utils::*};
+
+    #[derive(Default, Copy, Clone)]
+    struct CallbackMonitor {
+        invoke_count: u64,
+        last_reported_count: u32,
+    }
+
+    /// Utility to create a tween for testing.
+    fn make_test_tween() -> Tween<Transform> {
+        Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+    }
+
+    /// Utility to create a test environment to tick a tween.
+    fn make_test_env() -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<Events<TweenCompleted>>();
+        world.init_resource::<Events<MarkerReached>>();
+        let entity = world.spawn(Transform::default()).id();
+        (world, entity)
+    }
+
+    /// Manually tick a test tweenable targeting a component.
+    fn manual_tick_component<T: Component>(
+        duration: Duration,
+        tween: &mut dyn Tweenable<T>,
+        world: &mut World,
+        entity: Entity,
+    ) -> TweenState {
+        world.resource_scope(
+            |world: &mut World, mut events: Mut<Events<TweenCompleted>>| {
+                let transform = world.get_mut::<T>(entity).unwrap();
+                let mut target = ComponentTarget::new(transform);
+                let mut events = TweenEvents::new(&mut events);
+                let state = tween.tick(duration, &mut target, entity, &mut events);
+                for op in events.take_deferred() {
+                    op(world);
+                }
+                state
+            },
+        )
+    }
+
+    fn manual_tick_backward_component<T: Component>(
+        duration: Duration,
+        tween: &mut dyn Tweenable<T>,
+        world: &mut World,
+        entity: Entity,
+    ) -> TweenState {
+        world.resource_scope(
+            |world: &mut World, mut events: Mut<Events<TweenCompleted>>| {
+                let transform = world.get_mut::<T>(entity).unwrap();
+                let mut target = ComponentTarget::new(transform);
+                let mut events = TweenEvents::new(&mut events);
+                let state = tween.tick_backward(duration, &mut target, entity, &mut events);
+                for op in events.take_deferred() {
+                    op(world);
+                }
+                state
+            },
+        )
+    }
+
+    #[test]
+    fn anim_clock_precision() {
+        let duration = Duration::from_millis(1);
+        let mut clock = AnimClock::new(duration);
+        clock.total_duration = TotalDuration::Infinite;
+
+        let test_ticks = [
+            Duration::from_micros(123),
+            Duration::from_millis(1),
+            Duration::from_secs_f32(1. / 24.),
+            Duration::from_secs_f32(1. / 30.),
+            Duration::from_secs_f32(1. / 60.),
+            Duration::from_secs_f32(1. / 120.),
+            Duration::from_secs_f32(1. / 144.),
+            Duration::from_secs_f32(1. / 240.),
+        ];
+
+        let mut times_completed = 0;
+        let mut total_duration = Duration::ZERO;
+        for i in 0..10_000_000 {
+            let tick = test_ticks[i % test_ticks.len()];
+            times_completed += clock.tick(tick).1;
+            total_duration += tick;
+        }
+
+        assert_eq!(
+            (total_duration.as_secs_f64() / duration.as_secs_f64()) as i32,
+            times_completed
+        );
+    }
+
+    #[test]
+    fn into_repeat_count() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_repeat_count(5);
+        assert_eq!(
+            tween.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(5))
+        );
+
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_repeat_count(Duration::from_secs(3));
+        assert_eq!(
+            tween.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(3))
+        );
+    }
+
+    /// Test ticking of a single tween in isolation.
+    #[test]
+    fn tween_tick() {
+        for tweening_direction in &[TweeningDirection::Forward, TweeningDirection::Backward] {
+            for (count, strategy) in &[
+                (RepeatCount::Finite(1), RepeatStrategy::default()),
+                (RepeatCount::Infinite, RepeatStrategy::Repeat),
+                (RepeatCount::Finite(2), RepeatStrategy::Repeat),
+                (RepeatCount::Infinite, RepeatStrategy::MirroredRepeat),
+                (RepeatCount::Finite(2), RepeatStrategy::MirroredRepeat),
+            ] {
+                println!(
+                    "TweeningType: count={count:?} strategy={strategy:?} dir={tweening_direction:?}",
+                );
+
+                // Create a linear tween over 1 second
+                let mut tween = make_test_tween()
+                    .with_direction(*tweening_direction)
+                    .with_repeat_count(*count)
+                    .with_repeat_strategy(*strategy);
+                assert_eq!(tween.direction(), *tweening_direction);
+                assert!(tween.on_completed.is_none());
+                assert!(tween.event_data.is_none());
+
+                let (mut world, entity) = make_test_env();
+                let mut event_reader_system_state: SystemState<EventReader<TweenCompleted>> =
+                    SystemState::new(&mut world);
+
+                // Register callbacks to count started/ended events
+                let callback_monitor = Arc::new(Mutex::new(CallbackMonitor::default()));
+                let cb_mon_ptr = Arc::clone(&callback_monitor);
+                let reference_entity = entity;
+                tween.set_completed(move |completed_entity, tween| {
+                    assert_eq!(completed_entity, reference_entity);
+                    let mut cb_mon = cb_mon_ptr.lock().unwrap();
+                    cb_mon.invoke_count += 1;
+                    cb_mon.last_reported_count = tween.times_completed();
+                });
+                assert!(tween.on_completed.is_some());
+                assert!(tween.event_data.is_none());
+                assert_eq!(callback_monitor.lock().unwrap().invoke_count, 0);
+
+                // Activate event sending
+                const USER_DATA: u64 = 54789; // dummy
+                tween.set_completed_event(USER_DATA);
+                assert!(tween.event_data.is_some());
+                assert_eq!(tween.event_data.unwrap(), USER_DATA);
+
+                // Loop over 2.2 seconds, so greater than one ping-pong loop
+                let tick_duration = Duration::from_millis(200);
+                for i in 1..=11 {
+                    // Calculate expected values
+                    let (progress, times_completed, mut direction, expected_state, just_completed) =
+                        match count {
+                            RepeatCount::Finite(1) => {
+                                let progress = (i as f32 * 0.2).min(1.0);
+                                let times_completed = u32::from(i >= 5);
+                                let state = if i < 5 {
+                                    TweenState::Active
+                                } else {
+                                    TweenState::Completed
+                                };
+                                let just_completed = i == 5;
+                                (
+                                    progress,
+                                    times_completed,
+                                    TweeningDirection::Forward,
+                                    state,
+                                    just_completed,
+                                )
+                            }
+                            RepeatCount::Finite(count) => {
+                                let total_progress = i as f32 * 0.2;
+                                let progress = if total_progress >= *count as f32 {
+                                    1.
+                                } else {
+                                    total_progress.fract()
+                                };
+                                if *strategy == RepeatStrategy::Repeat {
+                                    let times_completed = i / 5;
+                                    let just_completed = i % 5 == 0;
+                                    (
+                                        progress,
+                                        times_completed,
+                                        TweeningDirection::Forward,
+                                        if i < 10 {
+                                            TweenState::Active
+                                        } else {
+                                            TweenState::Completed
+                                        },
+                                        just_completed,
+                                    )
+                                } else {
+                                    let i5 = i % 5;
+                                    let times_completed = i / 5;
+                                    // Once Completed, the direction doesn't change
+                                    let direction = if i >= 5 {
+                                        TweeningDirection::Backward
+                                    } else {
+                                        TweeningDirection::Forward
+                                    };
+                                    let just_completed = i5 == 0;
+                                    (
+                                        progress,
+                                        times_completed,
+                                        direction,
+                                        if i < 10 {
+                                            TweenState::Active
+                                        } else {
+                                            TweenState::Completed
+                                        },
+                                        just_completed,
+                                    )
+                                }
+                            }
+                            RepeatCount::Infinite => {
+                                let progress = (i as f32 * 0.2).fract();
+                                if *strategy == RepeatStrategy::Repeat {
+                                    let times_completed = i / 5;
+                                    let just_completed = i % 5 == 0;
+                                    (
+                                        progress,
+                                        times_completed,
+                                        TweeningDirection::Forward,
+                                        TweenState::Active,
+                                        just_completed,
+                                    )
+                                } else {
+                                    let i5 = i % 5;
+                                    let times_completed = i / 5;
+                                    let i10 = i % 10;
+                                    let direction = if i10 >= 5 {
+                                        TweeningDirection::Backward
+                                    } else {
+                                        TweeningDirection::Forward
+                                    };
+                                    let just_completed = i5 == 0;
+                                    (
+                                        progress,
+                                        times_completed,
+                                        direction,
+                                        TweenState::Active,
+                                        just_completed,
+                                    )
+                                }
+                            }
+                            RepeatCount::For(_) => panic!("Untested"),
+                        };
+                    let factor = if tweening_direction.is_backward() {
+                        direction = !direction;
+                        1. - progress
+                    } else {
+                        progress
+                    };
+                    let expected_translation = if direction.is_forward() {
+                        Vec3::splat(progress)
+                    } else {
+                        Vec3::splat(1. - progress)
+                    };
+                    println!(
+                        "Expected: progress={} factor={} times_completed={} direction={:?} state={:?} just_completed={} translation={:?}",
+                        progress, factor, times_completed, direction, expected_state, just_completed, expected_translation
+                    );
+
+                    // Tick the tween
+                    let actual_state =
+                        manual_tick_component(tick_duration, &mut tween, &mut world, entity);
+
+                    // Propagate events
+                    {
+                        let mut events = world.resource_mut::<Events<TweenCompleted>>();
+                        events.update();
+                    }
+
+                    // Check actual values
+                    assert_eq!(tween.direction(), direction);
+                    assert_eq!(actual_state, expected_state);
+                    assert_approx_eq!(tween.progress(), progress);
+                    assert_eq!(tween.times_completed(), times_completed);
+                    let transform = world.entity(entity).get::<Transform>().unwrap();
+                    assert!(transform
+                        .translation
+                        .abs_diff_eq(expected_translation, 1e-5));
+                    assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+                    let cb_mon = callback_monitor.lock().unwrap();
+                    assert_eq!(cb_mon.invoke_count, times_completed as u64);
+                    assert_eq!(cb_mon.last_reported_count, times_completed);
+                    {
+                        let mut event_reader = event_reader_system_state.get_mut(&mut world);
+                        let event = event_reader.iter().next();
+                        if just_completed {
+                            assert!(event.is_some());
+                            if let Some(event) = event {
+                                assert_eq!(event.entity, entity);
+                                assert_eq!(event.user_data, USER_DATA);
+                            }
+                        } else {
+                            assert!(event.is_none());
+                        }
+                    }
+                }
+
+                // Rewind
+                tween.rewind();
+                assert_eq!(tween.direction(), *tweening_direction); // does not change
+                assert_approx_eq!(tween.progress(), 0.);
+                assert_eq!(tween.times_completed(), 0);
+
+                // Dummy tick to update target
+                let actual_state =
+                    manual_tick_component(Duration::ZERO, &mut tween, &mut world, entity);
+                assert_eq!(actual_state, TweenState::Active);
+                let expected_translation = if tweening_direction.is_backward() {
+                    Vec3::ONE
+                } else {
+                    Vec3::ZERO
+                };
+                let transform = world.entity(entity).get::<Transform>().unwrap();
+                assert!(transform
+                    .translation
+                    .abs_diff_eq(expected_translation, 1e-5));
+                assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+
+                // Clear callback
+                tween.clear_completed();
+                assert!(tween.on_completed.is_none());
+
+                // Clear event sending
+                tween.clear_completed_event();
+                assert!(tween.event_data.is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn tween_dir() {
+        let mut tween = make_test_tween();
+
+        // Default
+        assert_eq!(tween.direction(), TweeningDirection::Forward);
+        assert_approx_eq!(tween.progress(), 0.0);
+
+        // no-op
+        tween.set_direction(TweeningDirection::Forward);
+        assert_eq!(tween.direction(), TweeningDirection::Forward);
+        assert_approx_eq!(tween.progress(), 0.0);
+
+        // Backward
+        tween.set_direction(TweeningDirection::Backward);
+        assert_eq!(tween.direction(), TweeningDirection::Backward);
+        // progress is independent of direction
+        assert_approx_eq!(tween.progress(), 0.0);
+
+        // Progress-invariant
+        tween.set_direction(TweeningDirection::Forward);
+        tween.set_progress(0.3);
+        assert_approx_eq!(tween.progress(), 0.3);
+        tween.set_direction(TweeningDirection::Backward);
+        // progress is independent of direction
+        assert_approx_eq!(tween.progress(), 0.3);
+
+        let (mut world, entity) = make_test_env();
+
+        // Progress always increases alongside the current direction
+        tween.set_direction(TweeningDirection::Backward);
+        assert_approx_eq!(tween.progress(), 0.3);
+        manual_tick_component(Duration::from_millis(100), &mut tween, &mut world, entity);
+        assert_approx_eq!(tween.progress(), 0.4);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.6), 1e-5));
+    }
+
+    #[test]
+    fn tweenable_default_direction() {
+        struct ForwardOnly(Duration);
+
+        impl Tweenable<Transform> for ForwardOnly {
+            fn duration(&self) -> Duration {
+                Duration::from_secs(1)
+            }
+
+            fn total_duration(&self) -> TotalDuration {
+                TotalDuration::Finite(self.duration())
+            }
+
+            fn set_elapsed(&mut self, elapsed: Duration) {
+                self.0 = elapsed;
+            }
+
+            fn elapsed(&self) -> Duration {
+                self.0
+            }
+
+            fn tick(
+                &mut self,
+                delta: Duration,
+                _target: &mut dyn Targetable<Transform>,
+                _entity: Entity,
+                _events: &mut TweenEvents<'_>,
+            ) -> TweenState {
+                self.0 += delta;
+                TweenState::Active
+            }
+
+            fn rewind(&mut self) {
+                self.0 = Duration::ZERO;
+            }
+        }
+
+        // Custom tweenables not supporting playing backward ignore the direction
+        let mut tweenable = ForwardOnly(Duration::ZERO);
+        assert_eq!(tweenable.direction(), TweeningDirection::Forward);
+        tweenable.set_direction(TweeningDirection::Backward);
+        assert_eq!(tweenable.direction(), TweeningDirection::Forward);
+    }
+
+    #[test]
+    fn tween_elapsed() {
+        let mut tween = make_test_tween();
+
+        let duration = tween.duration();
+        let elapsed = tween.elapsed();
+
+        assert_eq!(elapsed, Duration::ZERO);
+        assert_eq!(duration, Duration::from_secs(1));
+
+        for ms in [0, 1, 500, 100, 300, 999, 847, 1000, 900] {
+            let elapsed = Duration::from_millis(ms);
+            tween.set_elapsed(elapsed);
+            assert_eq!(tween.elapsed(), elapsed);
+
+            let progress = (elapsed.as_secs_f64() / duration.as_secs_f64()) as f32;
+            assert_approx_eq!(tween.progress(), progress);
+
+            let times_completed = u32::from(ms == 1000);
+            assert_eq!(tween.times_completed(), times_completed);
+        }
+    }
+
+    /// Test ticking a sequence of tweens.
+    #[test]
+    fn seq_tick() {
+        let tween1 = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let tween2 = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformRotationLens {
+                start: Quat::IDENTITY,
+                end: Quat::from_rotation_x(90_f32.to_radians()),
+            },
+        );
+        let mut seq = tween1.then(tween2);
+
+        let (mut world, entity) = make_test_env();
+
+        for i in 1..=16 {
+            let state =
+                manual_tick_component(Duration::from_millis(200), &mut seq, &mut world, entity);
+            let transform = world.entity(entity).get::<Transform>().unwrap();
+            if i < 5 {
+                assert_eq!(state, TweenState::Active);
+                let r = i as f32 * 0.2;
+                assert_eq!(*transform, Transform::from_translation(Vec3::splat(r)));
+            } else if i < 10 {
+                assert_eq!(state, TweenState::Active);
+                let alpha_deg = (18 * (i - 5)) as f32;
+                assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+                assert!(transform
+                    .rotation
+                    .abs_diff_eq(Quat::from_rotation_x(alpha_deg.to_radians()), 1e-5));
+            } else {
+                assert_eq!(state, TweenState::Completed);
+                assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+                assert!(transform
+                    .rotation
+                    .abs_diff_eq(Quat::from_rotation_x(90_f32.to_radians()), 1e-5));
+            }
+        }
+    }
+
+    /// Test crossing tween boundaries in one tick.
+    #[test]
+    fn seq_tick_boundaries() {
+        let mut seq = Sequence::new((0..3).map(|i| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::splat(i as f32),
+                    end: Vec3::splat((i + 1) as f32),
+                },
+            )
+            .with_repeat_count(RepeatCount::Finite(1))
+        }));
+
+        let (mut world, entity) = make_test_env();
+
+        // Tick halfway through the first tween, then in one tick:
+        // - Finish the first tween
+        // - Start and finish the second tween
+        // - Start the third tween
+        for delta_ms in [500, 2000] {
+            manual_tick_component(
+                Duration::from_millis(delta_ms),
+                &mut seq,
+                &mut world,
+                entity,
+            );
+        }
+        assert_eq!(seq.index(), 2);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(2.5), 1e-5));
+    }
+
+    /// Sequence::new() and various Sequence-specific methods
+    #[test]
+    fn seq_iter() {
+        let mut seq = Sequence::new((1..5).map(|i| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_millis(200 * i),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+        }));
+
+        let mut progress = 0.;
+        for i in 1..5 {
+            assert_eq!(seq.index(), i - 1);
+            assert_approx_eq!(seq.progress(), progress);
+            let duration = Duration::from_millis(200 * i as u64);
+            assert_eq!(seq.current().duration(), duration);
+            progress += 0.25;
+            seq.set_progress(progress);
+            assert_eq!(seq.times_completed(), u32::from(i == 4));
+        }
+
+        seq.rewind();
+        assert_eq!(seq.progress(), 0.);
+        assert_eq!(seq.times_completed(), 0);
+    }
+
+    /// Sequence::from_single()
+    #[test]
+    fn seq_from_single() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
                 start: Vec3::ZERO,
                 end: Vec3::ONE,
             },
+        );
+        let seq = Sequence::from_single(tween);
+
+        assert_eq!(seq.duration(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn seq_empty() {
+        let mut seq = Sequence::<Transform>::with_capacity(4);
+        assert_eq!(seq.duration(), Duration::ZERO);
+
+        // Seeking an empty sequence is a no-op
+        seq.set_elapsed(Duration::from_millis(100));
+        seq.set_progress(0.5);
+        seq.set_direction(TweeningDirection::Backward);
+        assert_eq!(seq.direction(), TweeningDirection::Backward);
+        seq.rewind();
+
+        let (mut world, entity) = make_test_env();
+        let state = manual_tick_component(Duration::from_millis(100), &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+    }
+
+    #[test]
+    fn composite_zero_duration_infinite() {
+        let zero = || {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::ZERO,
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+        };
+        let mut tracks = Tracks::new([zero(), zero()]).with_repeat_count(RepeatCount::Infinite);
+        let mut seq = Sequence::new([zero(), zero()]).with_repeat_count(RepeatCount::Infinite);
+        assert_eq!(tracks.duration(), Duration::ZERO);
+        assert_eq!(seq.duration(), Duration::ZERO);
+
+        let (mut world, entity) = make_test_env();
+        for _ in 0..3 {
+            let state =
+                manual_tick_component(Duration::from_millis(100), &mut tracks, &mut world, entity);
+            assert_eq!(state, TweenState::Active);
+            let state =
+                manual_tick_component(Duration::from_millis(100), &mut seq, &mut world, entity);
+            assert_eq!(state, TweenState::Active);
+        }
+    }
+
+    #[test]
+    fn seq_elapsed() {
+        let mut seq = Sequence::new((1..5).map(|i| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_millis(200 * i),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+        }));
+
+        let mut elapsed = Duration::ZERO;
+        for i in 1..5 {
+            assert_eq!(seq.index(), i - 1);
+            assert_eq!(seq.elapsed(), elapsed);
+            let duration = Duration::from_millis(200 * i as u64);
+            assert_eq!(seq.current().duration(), duration);
+            elapsed += duration;
+            seq.set_elapsed(elapsed);
+            assert_eq!(seq.times_completed(), u32::from(i == 4));
+        }
+    }
+
+    /// Test seeking a sequence animating different fields with its children.
+    #[test]
+    fn seq_seek() {
+        let tween1 = make_test_tween();
+        let tween2 = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformRotationLens {
+                start: Quat::IDENTITY,
+                end: Quat::from_rotation_x(90_f32.to_radians()),
+            },
+        );
+        let tween3 = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformScaleLens {
+                start: Vec3::ONE,
+                end: Vec3::splat(2.),
+            },
+        );
+        let mut seq = tween1.then(tween2).then(tween3);
+
+        let (mut world, entity) = make_test_env();
+
+        // Seek into the last tween, without ever playing the previous ones
+        seq.set_elapsed(Duration::from_millis(2500));
+        assert_eq!(seq.index(), 2);
+        assert_eq!(seq.tweens[0].elapsed(), Duration::from_secs(1));
+        assert_eq!(seq.tweens[1].elapsed(), Duration::from_secs(1));
+        assert_eq!(seq.tweens[2].elapsed(), Duration::from_millis(500));
+        let state = manual_tick_component(Duration::ZERO, &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_x(90_f32.to_radians()), 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(1.5), 1e-5));
+
+        // Seek backward into the first tween; later tweens are back to their start
+        seq.set_elapsed(Duration::from_millis(200));
+        assert_eq!(seq.index(), 0);
+        assert_eq!(seq.tweens[0].elapsed(), Duration::from_millis(200));
+        assert_eq!(seq.tweens[1].elapsed(), Duration::ZERO);
+        assert_eq!(seq.tweens[2].elapsed(), Duration::ZERO);
+        manual_tick_component(Duration::ZERO, &mut seq, &mut world, entity);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.2), 1e-5));
+
+        // Resume playback from there
+        manual_tick_component(Duration::from_millis(1300), &mut seq, &mut world, entity);
+        assert_eq!(seq.index(), 1);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_x(45_f32.to_radians()), 1e-5));
+    }
+
+    /// Test ticking parallel tracks of tweens.
+    #[test]
+    fn tracks_tick() {
+        let tween1 = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_millis(1000),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let tween2 = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_millis(800), // shorter
+            TransformRotationLens {
+                start: Quat::IDENTITY,
+                end: Quat::from_rotation_x(90_f32.to_radians()),
+            },
+        );
+        let mut tracks = Tracks::new([tween1, tween2]);
+        assert_eq!(tracks.duration(), Duration::from_secs(1)); // max(1., 0.8)
+
+        let (mut world, entity) = make_test_env();
+
+        for i in 1..=6 {
+            let state =
+                manual_tick_component(Duration::from_millis(200), &mut tracks, &mut world, entity);
+            let transform = world.entity(entity).get::<Transform>().unwrap();
+            if i < 5 {
+                assert_eq!(state, TweenState::Active);
+                assert_eq!(tracks.times_completed(), 0);
+                let r = i as f32 * 0.2;
+                assert_approx_eq!(tracks.progress(), r);
+                let alpha_deg = 22.5 * i as f32;
+                assert!(transform.translation.abs_diff_eq(Vec3::splat(r), 1e-5));
+                assert!(transform
+                    .rotation
+                    .abs_diff_eq(Quat::from_rotation_x(alpha_deg.to_radians()), 1e-5));
+            } else {
+                assert_eq!(state, TweenState::Completed);
+                assert_eq!(tracks.times_completed(), 1);
+                assert_approx_eq!(tracks.progress(), 1.);
+                assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+                assert!(transform
+                    .rotation
+                    .abs_diff_eq(Quat::from_rotation_x(90_f32.to_radians()), 1e-5));
+            }
+        }
+
+        tracks.rewind();
+        assert_eq!(tracks.times_completed(), 0);
+        assert_approx_eq!(tracks.progress(), 0.);
+
+        tracks.set_progress(0.9);
+        assert_approx_eq!(tracks.progress(), 0.9);
+        // tick to udpate state (set_progress() does not update state)
+        let state = manual_tick_component(Duration::ZERO, &mut tracks, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(tracks.times_completed(), 0);
+
+        tracks.set_progress(3.2);
+        assert_approx_eq!(tracks.progress(), 1.);
+        // tick to udpate state (set_progress() does not update state)
+        let state = manual_tick_component(Duration::ZERO, &mut tracks, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(tracks.times_completed(), 1); // no looping
+
+        tracks.set_progress(-0.5);
+        assert_approx_eq!(tracks.progress(), 0.);
+        // tick to udpate state (set_progress() does not update state)
+        let state = manual_tick_component(Duration::ZERO, &mut tracks, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(tracks.times_completed(), 0); // no looping
+    }
+
+    /// Utility to create a sequence of 2 chained tweens for testing, moving along
+    /// the X axis from 0 to 1 then from 1 to 2, raising events with the index of
+    /// the tween.
+    fn make_test_seq() -> Sequence<Transform> {
+        Sequence::new((0..2).map(|i| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::X * i as f32,
+                    end: Vec3::X * (i + 1) as f32,
+                },
+            )
+            .with_completed_event(i)
+        }))
+    }
+
+    /// Drain all pending [`TweenCompleted`] events, returning their user data.
+    fn drain_events(world: &mut World) -> Vec<u64> {
+        let mut events = world.resource_mut::<Events<TweenCompleted>>();
+        events.drain().map(|ev| ev.user_data).collect()
+    }
+
+    fn drain_markers(world: &mut World) -> Vec<u64> {
+        let mut events = world.resource_mut::<Events<MarkerReached>>();
+        events.drain().map(|ev| ev.user_data).collect()
+    }
+
+    /// Get the X position of the test entity.
+    fn position_x(world: &World, entity: Entity) -> f32 {
+        world
+            .entity(entity)
+            .get::<Transform>()
+            .unwrap()
+            .translation
+            .x
+    }
+
+    /// Test repeating a whole sequence.
+    #[test]
+    fn seq_repeat() {
+        let mut seq = make_test_seq().with_repeat_count(3);
+        assert_eq!(seq.duration(), Duration::from_secs(2));
+        assert_eq!(
+            seq.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(6))
+        );
+
+        let (mut world, entity) = make_test_env();
+
+        let mut all_events = vec![];
+        for i in 1..=30 {
+            let state =
+                manual_tick_component(Duration::from_millis(250), &mut seq, &mut world, entity);
+            all_events.append(&mut drain_events(&mut world));
+            let elapsed_ms = i * 250;
+            let x = (elapsed_ms % 2000) as f32 / 1000.;
+            if i < 24 {
+                assert_eq!(state, TweenState::Active);
+                assert_eq!(seq.times_completed(), elapsed_ms / 2000);
+                assert_approx_eq!(position_x(&world, entity), x);
+                assert_eq!(seq.index(), (elapsed_ms % 2000 / 1000) as usize);
+            } else {
+                assert_eq!(state, TweenState::Completed);
+                assert_eq!(seq.times_completed(), 3);
+                assert_approx_eq!(seq.progress(), 1.);
+                assert_approx_eq!(position_x(&world, entity), 2.);
+            }
+        }
+        assert_eq!(all_events, vec![0, 1, 0, 1, 0, 1]);
+
+        // Crossing several iterations in a single tick raises all events
+        seq.rewind();
+        manual_tick_component(Duration::from_millis(4500), &mut seq, &mut world, entity);
+        assert_eq!(drain_events(&mut world), vec![0, 1, 0, 1]);
+        assert_eq!(seq.times_completed(), 2);
+        assert_eq!(seq.index(), 0);
+        assert_approx_eq!(position_x(&world, entity), 0.5);
+
+        // Ending exactly on an iteration boundary applies the start of the next
+        // iteration, like a single tween does
+        manual_tick_component(Duration::from_millis(1500), &mut seq, &mut world, entity);
+        assert_eq!(drain_events(&mut world), vec![0, 1]);
+        assert_eq!(seq.times_completed(), 3);
+        assert_eq!(seq.index(), 1);
+        assert_approx_eq!(position_x(&world, entity), 2.);
+    }
+
+    /// Test repeating a sequence with a callback on a child.
+    #[test]
+    fn seq_repeat_callback() {
+        let callback_monitor = Arc::new(Mutex::new(CallbackMonitor::default()));
+        let cb_mon_ptr = Arc::clone(&callback_monitor);
+        let tween = make_test_tween().with_completed(move |_, tween| {
+            let mut cb_mon = cb_mon_ptr.lock().unwrap();
+            cb_mon.invoke_count += 1;
+            cb_mon.last_reported_count = tween.times_completed();
+        });
+        let mut seq = Delay::new(Duration::from_millis(500))
+            .then(tween)
+            .with_repeat_count(RepeatCount::Infinite);
+        assert_eq!(seq.total_duration(), TotalDuration::Infinite);
+
+        let (mut world, entity) = make_test_env();
+
+        for i in 1..=10 {
+            let state =
+                manual_tick_component(Duration::from_millis(750), &mut seq, &mut world, entity);
+            assert_eq!(state, TweenState::Active);
+            let cb_mon = callback_monitor.lock().unwrap();
+            assert_eq!(cb_mon.invoke_count, i / 2);
+            assert_eq!(cb_mon.last_reported_count, u32::from(i >= 2));
+        }
+    }
+
+    /// Test mirrored repeating of a whole sequence.
+    #[test]
+    fn seq_mirrored_repeat() {
+        let mut seq = make_test_seq()
+            .with_repeat_count(4)
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+
+        let (mut world, entity) = make_test_env();
+
+        let mut all_events = vec![];
+        for i in 1..=36 {
+            let state =
+                manual_tick_component(Duration::from_millis(250), &mut seq, &mut world, entity);
+            all_events.append(&mut drain_events(&mut world));
+            let elapsed_ms = i * 250;
+            let local_ms = elapsed_ms % 4000;
+            let x = if local_ms <= 2000 {
+                local_ms as f32 / 1000.
+            } else {
+                (4000 - local_ms) as f32 / 1000.
+            };
+            if i < 32 {
+                assert_eq!(state, TweenState::Active);
+                assert_eq!(seq.times_completed(), elapsed_ms / 2000);
+                assert_approx_eq!(position_x(&world, entity), x);
+            } else {
+                assert_eq!(state, TweenState::Completed);
+                assert_eq!(seq.times_completed(), 4);
+                assert_approx_eq!(position_x(&world, entity), 0.);
+            }
+        }
+        // Backward iterations raise events too, in reverse order
+        assert_eq!(all_events, vec![0, 1, 1, 0, 0, 1, 1, 0]);
+
+        // Large ticks crossing child and iteration boundaries
+        seq.rewind();
+        manual_tick_component(Duration::from_millis(2700), &mut seq, &mut world, entity);
+        assert_eq!(seq.index(), 1);
+        assert_approx_eq!(position_x(&world, entity), 1.3);
+        manual_tick_component(Duration::from_millis(1000), &mut seq, &mut world, entity);
+        assert_eq!(seq.index(), 0);
+        assert_approx_eq!(position_x(&world, entity), 0.3);
+        manual_tick_component(Duration::from_millis(1000), &mut seq, &mut world, entity);
+        assert_eq!(seq.index(), 0);
+        assert_approx_eq!(position_x(&world, entity), 0.7);
+    }
+
+    /// Test repeating parallel tracks.
+    #[test]
+    fn tracks_repeat() {
+        let tween1 = make_test_tween().with_completed_event(0);
+        let tween2 = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_millis(500),
+            TransformScaleLens {
+                start: Vec3::ONE,
+                end: Vec3::splat(2.),
+            },
         )
-        .with_repeat_count(5);
+        .with_completed_event(1);
+        let mut tracks = Tracks::new([tween1, tween2])
+            .with_repeat_count(2)
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
         assert_eq!(
-            tween.total_duration(),
-            TotalDuration::Finite(Duration::from_secs(5))
+            tracks.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(2))
         );
 
-        let tween = Tween::new(
+        let (mut world, entity) = make_test_env();
+
+        let mut all_events = vec![];
+        for i in 1..=10 {
+            let state =
+                manual_tick_component(Duration::from_millis(250), &mut tracks, &mut world, entity);
+            all_events.append(&mut drain_events(&mut world));
+            let elapsed_ms = i * 250;
+            let t = if elapsed_ms <= 1000 {
+                elapsed_ms
+            } else {
+                2000_u32.saturating_sub(elapsed_ms)
+            };
+            let transform = world.entity(entity).get::<Transform>().unwrap();
+            assert!(transform
+                .translation
+                .abs_diff_eq(Vec3::splat(t as f32 / 1000.), 1e-5));
+            let scale = 1. + (t.min(500) as f32 / 500.);
+            assert!(transform.scale.abs_diff_eq(Vec3::splat(scale), 1e-5));
+            assert_eq!(
+                state,
+                if i < 8 {
+                    TweenState::Active
+                } else {
+                    TweenState::Completed
+                }
+            );
+        }
+        assert_eq!(all_events, vec![1, 0, 0, 1]);
+
+        // Repeat strategy
+        let mut tracks = Tracks::new([make_test_tween().with_completed_event(0)])
+            .with_repeat_count(Duration::from_millis(2500));
+        manual_tick_component(Duration::from_millis(2200), &mut tracks, &mut world, entity);
+        assert_eq!(drain_events(&mut world), vec![0, 0]);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.2), 1e-5));
+        let state =
+            manual_tick_component(Duration::from_millis(500), &mut tracks, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(tracks.times_completed(), 2);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+    }
+
+    /// Test playing a sequence backward.
+    #[test]
+    fn seq_backward() {
+        let mut seq = make_test_seq().with_direction(TweeningDirection::Backward);
+        assert_eq!(seq.direction(), TweeningDirection::Backward);
+        assert_eq!(seq.index(), 1);
+
+        let (mut world, entity) = make_test_env();
+
+        let mut all_events = vec![];
+        for i in 1..=10 {
+            let state =
+                manual_tick_component(Duration::from_millis(250), &mut seq, &mut world, entity);
+            all_events.append(&mut drain_events(&mut world));
+            let elapsed_ms = (i * 250).min(2000);
+            assert_approx_eq!(seq.progress(), elapsed_ms as f32 / 2000.);
+            assert_approx_eq!(position_x(&world, entity), 2. - elapsed_ms as f32 / 1000.);
+            if i < 8 {
+                assert_eq!(state, TweenState::Active);
+                assert_eq!(seq.index(), usize::from(elapsed_ms < 1000));
+            } else {
+                assert_eq!(state, TweenState::Completed);
+                assert_eq!(seq.index(), 0);
+            }
+        }
+        // Children complete in reverse order
+        assert_eq!(all_events, vec![1, 0]);
+
+        // Changing direction mid-way jumps to the mirrored point
+        let mut seq = make_test_seq();
+        manual_tick_component(Duration::from_millis(700), &mut seq, &mut world, entity);
+        assert_approx_eq!(position_x(&world, entity), 0.7);
+        seq.set_direction(TweeningDirection::Backward);
+        manual_tick_component(Duration::ZERO, &mut seq, &mut world, entity);
+        assert_eq!(seq.index(), 1);
+        assert_approx_eq!(position_x(&world, entity), 1.3);
+        manual_tick_component(Duration::from_millis(500), &mut seq, &mut world, entity);
+        assert_eq!(seq.index(), 0);
+        assert_approx_eq!(position_x(&world, entity), 0.8);
+        drain_events(&mut world);
+
+        // Nested delays and sequences play backward too, in reverse order
+        let mut seq = Delay::new(Duration::from_millis(500))
+            .with_completed_event(10)
+            .then(make_test_seq())
+            .with_direction(TweeningDirection::Backward);
+        manual_tick_component(Duration::from_millis(1500), &mut seq, &mut world, entity);
+        assert_eq!(drain_events(&mut world), vec![1]);
+        assert_approx_eq!(position_x(&world, entity), 0.5);
+        let state =
+            manual_tick_component(Duration::from_millis(1000), &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(drain_events(&mut world), vec![0, 10]);
+        assert_approx_eq!(position_x(&world, entity), 0.);
+
+        // Playing the sequence forward again plays its children in their own
+        // direction
+        seq.set_direction(TweeningDirection::Forward);
+        seq.rewind();
+        manual_tick_component(Duration::from_millis(1000), &mut seq, &mut world, entity);
+        assert_eq!(drain_events(&mut world), vec![10]);
+        assert_approx_eq!(position_x(&world, entity), 0.5);
+    }
+
+    /// Test playing parallel tracks backward.
+    #[test]
+    fn tracks_backward() {
+        let tween1 = make_test_tween().with_completed_event(0);
+        let tween2 = Tween::new(
             EaseMethod::Linear,
-            Duration::from_secs(1),
-            TransformPositionLens {
-                start: Vec3::ZERO,
-                end: Vec3::ONE,
+            Duration::from_millis(500),
+            TransformScaleLens {
+                start: Vec3::ONE,
+                end: Vec3::splat(2.),
             },
         )
-        .with_repeat_count(Duration::from_secs(3));
+        .with_completed_event(1);
+        let mut tracks = Tracks::new([tween1, tween2]).with_direction(TweeningDirection::Backward);
+        assert_eq!(tracks.direction(), TweeningDirection::Backward);
+
+        let (mut world, entity) = make_test_env();
+
+        // The shorter track starts late, to complete with the longer one
+        let mut all_events = vec![];
+        for i in 1..=4 {
+            let state =
+                manual_tick_component(Duration::from_millis(250), &mut tracks, &mut world, entity);
+            all_events.append(&mut drain_events(&mut world));
+            let t = 1000 - i * 250;
+            let transform = world.entity(entity).get::<Transform>().unwrap();
+            assert!(transform
+                .translation
+                .abs_diff_eq(Vec3::splat(t as f32 / 1000.), 1e-5));
+            let scale = if i < 2 { 1. } else { 1. + t as f32 / 500. };
+            assert!(transform.scale.abs_diff_eq(Vec3::splat(scale), 1e-5));
+            assert_eq!(
+                state,
+                if i < 4 {
+                    TweenState::Active
+                } else {
+                    TweenState::Completed
+                }
+            );
+        }
+        assert_eq!(all_events, vec![0, 1]);
+    }
+
+    /// Test ticking a sequence backward in time.
+    #[test]
+    fn seq_tick_backward() {
+        let mut seq = make_test_seq();
+        let (mut world, entity) = make_test_env();
+
+        manual_tick_component(Duration::from_millis(1500), &mut seq, &mut world, entity);
+        assert_eq!(drain_events(&mut world), vec![0]);
+        assert_approx_eq!(position_x(&world, entity), 1.5);
+
+        // Rewinding across a child boundary doesn't raise any event
+        let state = manual_tick_backward_component(
+            Duration::from_millis(700),
+            &mut seq,
+            &mut world,
+            entity,
+        );
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(seq.index(), 0);
+        assert_eq!(seq.elapsed(), Duration::from_millis(800));
+        assert_approx_eq!(position_x(&world, entity), 0.8);
+        assert!(drain_events(&mut world).is_empty());
+
+        // Reaching the start completes the backward playback
+        let state =
+            manual_tick_backward_component(Duration::from_secs(5), &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(seq.elapsed(), Duration::ZERO);
+        assert_approx_eq!(position_x(&world, entity), 0.);
+        assert!(drain_events(&mut world).is_empty());
+
+        // Playing forward again raises the events again
+        manual_tick_component(Duration::from_secs(2), &mut seq, &mut world, entity);
+        assert_eq!(drain_events(&mut world), vec![0, 1]);
+        assert_approx_eq!(position_x(&world, entity), 2.);
+    }
+
+    /// Test staggering parallel tracks.
+    #[test]
+    fn tracks_stagger() {
+        let tween1 = make_test_tween().with_completed_event(0);
+        let tween2 = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_millis(500),
+            TransformScaleLens {
+                start: Vec3::ONE,
+                end: Vec3::splat(2.),
+            },
+        )
+        .with_completed_event(1);
+        let mut tracks = Tracks::new([tween1, tween2]).with_stagger(Duration::from_millis(800));
+        assert_eq!(tracks.duration(), Duration::from_millis(1300));
         assert_eq!(
-            tween.total_duration(),
-            TotalDuration::Finite(Duration::from_secs(3))
+            tracks.total_duration(),
+            TotalDuration::Finite(Duration::from_millis(1300))
         );
+
+        let (mut world, entity) = make_test_env();
+
+        let mut all_events = vec![];
+        for i in 1..=6 {
+            let state =
+                manual_tick_component(Duration::from_millis(250), &mut tracks, &mut world, entity);
+            all_events.append(&mut drain_events(&mut world));
+            let elapsed_ms: u32 = (i * 250).min(1300);
+            let transform = world.entity(entity).get::<Transform>().unwrap();
+            let t = elapsed_ms.min(1000) as f32 / 1000.;
+            assert!(transform.translation.abs_diff_eq(Vec3::splat(t), 1e-5));
+            let scale = 1. + elapsed_ms.saturating_sub(800) as f32 / 500.;
+            assert!(transform.scale.abs_diff_eq(Vec3::splat(scale), 1e-5));
+            assert_eq!(
+                state,
+                if i < 6 {
+                    TweenState::Active
+                } else {
+                    TweenState::Completed
+                }
+            );
+        }
+        assert_eq!(all_events, vec![0, 1]);
+
+        // Playing backward mirrors the offsets
+        tracks.set_direction(TweeningDirection::Backward);
+        tracks.rewind();
+        manual_tick_component(Duration::from_millis(300), &mut tracks, &mut world, entity);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(1.4), 1e-5));
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        manual_tick_component(Duration::from_millis(500), &mut tracks, &mut world, entity);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
     }
 
-    /// Test ticking of a single tween in isolation.
     #[test]
-    fn tween_tick() {
-        for tweening_direction in &[TweeningDirection::Forward, TweeningDirection::Backward] {
-            for (count, strategy) in &[
-                (RepeatCount::Finite(1), RepeatStrategy::default()),
-                (RepeatCount::Infinite, RepeatStrategy::Repeat),
-                (RepeatCount::Finite(2), RepeatStrategy::Repeat),
-                (RepeatCount::Infinite, RepeatStrategy::MirroredRepeat),
-                (RepeatCount::Finite(2), RepeatStrategy::MirroredRepeat),
-            ] {
-                println!(
-                    "TweeningType: count={count:?} strategy={strategy:?} dir={tweening_direction:?}",
-                );
+    #[should_panic]
+    fn tracks_offsets_count_mismatch_panics() {
+        let _ = Tracks::new([make_test_tween(), make_test_tween()])
+            .with_offsets([Duration::from_secs(1)]);
+    }
+
+    fn make_scale_tween(duration_ms: u64) -> Tween<Transform> {
+        Tween::new(
+            EaseMethod::Linear,
+            Duration::from_millis(duration_ms),
+            TransformScaleLens {
+                start: Vec3::ONE,
+                end: Vec3::splat(2.),
+            },
+        )
+    }
+
+    #[test]
+    fn timeline_positions() {
+        let timeline = Timeline::new()
+            // [0, 1000]
+            .then(make_test_tween())
+            // [1200, 1700]
+            .add(
+                make_scale_tween(500),
+                TimelinePosition::AfterPrevious(Duration::from_millis(200)),
+            )
+            .with_label("impact", TimelinePosition::End)
+            // [1400, 2400]
+            .add(
+                make_test_tween(),
+                TimelinePosition::BeforePreviousEnd(Duration::from_millis(300)),
+            )
+            // [1500, 2000]
+            .add(
+                make_scale_tween(500),
+                TimelinePosition::WithPrevious(Duration::from_millis(100)),
+            )
+            // [1700, 2200]
+            .add(make_scale_tween(500), "impact")
+            .with_label(
+                "late",
+                TimelinePosition::AfterPrevious(Duration::from_secs(1)),
+            )
+            // [3000, 3100]
+            .add(make_scale_tween(100), Duration::from_secs(3));
+        assert_eq!(timeline.duration(), Duration::from_millis(3100));
+        assert_eq!(
+            timeline.total_duration(),
+            TotalDuration::Finite(Duration::from_millis(3100))
+        );
+        assert_eq!(timeline.label("impact"), Some(Duration::from_millis(1700)));
+        assert_eq!(timeline.label("late"), Some(Duration::from_millis(3200)));
+        assert_eq!(timeline.label("missing"), None);
+
+        let timeline: Timeline<Transform> = Timeline::new().add(
+            make_test_tween(),
+            TimelinePosition::BeforePreviousEnd(Duration::from_secs(1)),
+        );
+        assert_eq!(timeline.duration(), Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn timeline_unknown_label_panics() {
+        let _ = Timeline::new().add(make_test_tween(), "missing");
+    }
+
+    #[test]
+    fn timeline_tick() {
+        let mut timeline = Timeline::new()
+            .then(make_test_tween().with_completed_event(0))
+            .add(
+                make_scale_tween(500).with_completed_event(1),
+                TimelinePosition::AfterPrevious(Duration::from_millis(500)),
+            )
+            .with_label("impact", TimelinePosition::WithPrevious(Duration::ZERO));
+        assert_eq!(timeline.duration(), Duration::from_millis(2000));
+
+        let (mut world, entity) = make_test_env();
+
+        let mut all_events = vec![];
+        for i in 1..=8 {
+            let state = manual_tick_component(
+                Duration::from_millis(250),
+                &mut timeline,
+                &mut world,
+                entity,
+            );
+            all_events.append(&mut drain_events(&mut world));
+            let elapsed_ms: u32 = i * 250;
+            let transform = world.entity(entity).get::<Transform>().unwrap();
+            let t = elapsed_ms.min(1000) as f32 / 1000.;
+            assert!(transform.translation.abs_diff_eq(Vec3::splat(t), 1e-5));
+            let scale = 1. + elapsed_ms.saturating_sub(1500) as f32 / 500.;
+            assert!(transform.scale.abs_diff_eq(Vec3::splat(scale), 1e-5));
+            assert_eq!(
+                state,
+                if i < 8 {
+                    TweenState::Active
+                } else {
+                    TweenState::Completed
+                }
+            );
+        }
+        assert_eq!(all_events, vec![0, 1]);
+
+        // Seek to a label
+        timeline.rewind();
+        assert!(!timeline.seek_to_label("missing"));
+        assert_eq!(timeline.elapsed(), Duration::ZERO);
+        assert!(timeline.seek_to_label("impact"));
+        assert_eq!(timeline.elapsed(), Duration::from_millis(1500));
+        manual_tick_component(Duration::ZERO, &mut timeline, &mut world, entity);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+        manual_tick_component(
+            Duration::from_millis(250),
+            &mut timeline,
+            &mut world,
+            entity,
+        );
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(1.5), 1e-5));
+
+        // Backward, labels are mirrored
+        timeline.set_direction(TweeningDirection::Backward);
+        assert!(timeline.seek_to_label("impact"));
+        assert_eq!(timeline.elapsed(), Duration::from_millis(500));
+        manual_tick_component(
+            Duration::from_millis(750),
+            &mut timeline,
+            &mut world,
+            entity,
+        );
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.75), 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+    }
+
+    #[test]
+    fn timeline_overlap() {
+        let mut timeline = Timeline::new().then(make_test_tween()).add(
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::splat(2.),
+                    end: Vec3::splat(3.),
+                },
+            ),
+            TimelinePosition::BeforePreviousEnd(Duration::from_millis(500)),
+        );
+        assert_eq!(timeline.duration(), Duration::from_millis(1500));
+
+        let (mut world, entity) = make_test_env();
 
-                // Create a linear tween over 1 second
-                let mut tween = make_test_tween()
-                    .with_direction(*tweening_direction)
-                    .with_repeat_count(*count)
-                    .with_repeat_strategy(*strategy);
-                assert_eq!(tween.direction(), *tweening_direction);
-                assert!(tween.on_completed.is_none());
-                assert!(tween.event_data.is_none());
+        // Before the overlap, only the first child animates
+        manual_tick_component(
+            Duration::from_millis(250),
+            &mut timeline,
+            &mut world,
+            entity,
+        );
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.25), 1e-5));
+
+        // During the overlap, the child added last wins
+        manual_tick_component(
+            Duration::from_millis(500),
+            &mut timeline,
+            &mut world,
+            entity,
+        );
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(2.25), 1e-5));
+    }
 
-                let (mut world, entity) = make_test_env();
-                let mut event_reader_system_state: SystemState<EventReader<TweenCompleted>> =
-                    SystemState::new(&mut world);
+    #[test]
+    fn marked_tick() {
+        let callback_monitor = Arc::new(Mutex::new(CallbackMonitor::default()));
+        let cb_mon_ptr = Arc::clone(&callback_monitor);
+        let tween = make_test_tween()
+            .with_repeat_count(2)
+            .with_completed_event(9);
+        let mut marked =
+            Marked::new(tween)
+                .with_marker(Marker::at_progress(0.5).with_event(2).with_callback(
+                    move |_, marker| {
+                        let mut cb_mon = cb_mon_ptr.lock().unwrap();
+                        cb_mon.invoke_count += 1;
+                        assert_eq!(marker.user_data(), Some(2));
+                    },
+                ))
+                .with_marker(Marker::at_progress(0.).with_event(0))
+                .with_marker(Marker::at_time(Duration::from_millis(750)).with_event(3))
+                .with_marker(Marker::at_time(Duration::from_millis(250)).with_event(1))
+                .with_marker(Marker::at_progress(1.).with_event(4));
+        assert_eq!(marked.duration(), Duration::from_secs(1));
+        assert_eq!(
+            marked.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(2))
+        );
 
-                // Register callbacks to count started/ended events
-                let callback_monitor = Arc::new(Mutex::new(CallbackMonitor::default()));
-                let cb_mon_ptr = Arc::clone(&callback_monitor);
-                let reference_entity = entity;
-                tween.set_completed(move |completed_entity, tween| {
-                    assert_eq!(completed_entity, reference_entity);
-                    let mut cb_mon = cb_mon_ptr.lock().unwrap();
-                    cb_mon.invoke_count += 1;
-                    cb_mon.last_reported_count = tween.times_completed();
-                });
-                assert!(tween.on_completed.is_some());
-                assert!(tween.event_data.is_none());
-                assert_eq!(callback_monitor.lock().unwrap().invoke_count, 0);
+        let (mut world, entity) = make_test_env();
 
-                // Activate event sending
-                const USER_DATA: u64 = 54789; // dummy
-                tween.set_completed_event(USER_DATA);
-                assert!(tween.event_data.is_some());
-                assert_eq!(tween.event_data.unwrap(), USER_DATA);
+        // A zero delta doesn't reach any marker
+        manual_tick_component(Duration::ZERO, &mut marked, &mut world, entity);
+        assert!(drain_markers(&mut world).is_empty());
 
-                // Loop over 2.2 seconds, so greater than one ping-pong loop
-                let tick_duration = Duration::from_millis(200);
-                for i in 1..=11 {
-                    // Calculate expected values
-                    let (progress, times_completed, mut direction, expected_state, just_completed) =
-                        match count {
-                            RepeatCount::Finite(1) => {
-                                let progress = (i as f32 * 0.2).min(1.0);
-                                let times_completed = u32::from(i >= 5);
-                                let state = if i < 5 {
-                                    TweenState::Active
-                                } else {
-                                    TweenState::Completed
-                                };
-                                let just_completed = i == 5;
-                                (
-                                    progress,
-                                    times_completed,
-                                    TweeningDirection::Forward,
-                                    state,
-                                    just_completed,
-                                )
-                            }
-                            RepeatCount::Finite(count) => {
-                                let total_progress = i as f32 * 0.2;
-                                let progress = if total_progress >= *count as f32 {
-                                    1.
-                                } else {
-                                    total_progress.fract()
-                                };
-                                if *strategy == RepeatStrategy::Repeat {
-                                    let times_completed = i / 5;
-                                    let just_completed = i % 5 == 0;
-                                    (
-                                        progress,
-                                        times_completed,
-                                        TweeningDirection::Forward,
-                                        if i < 10 {
-                                            TweenState::Active
-                                        } else {
-                                            TweenState::Completed
-                                        },
-                                        just_completed,
-                                    )
-                                } else {
-                                    let i5 = i % 5;
-                                    let times_completed = i / 5;
-                                    // Once Completed, the direction doesn't change
-                                    let direction = if i >= 5 {
-                                        TweeningDirection::Backward
-                                    } else {
-                                        TweeningDirection::Forward
-                                    };
-                                    let just_completed = i5 == 0;
-                                    (
-                                        progress,
-                                        times_completed,
-                                        direction,
-                                        if i < 10 {
-                                            TweenState::Active
-                                        } else {
-                                            TweenState::Completed
-                                        },
-                                        just_completed,
-                                    )
-                                }
-                            }
-                            RepeatCount::Infinite => {
-                                let progress = (i as f32 * 0.2).fract();
-                                if *strategy == RepeatStrategy::Repeat {
-                                    let times_completed = i / 5;
-                                    let just_completed = i % 5 == 0;
-                                    (
-                                        progress,
-                                        times_completed,
-                                        TweeningDirection::Forward,
-                                        TweenState::Active,
-                                        just_completed,
-                                    )
-                                } else {
-                                    let i5 = i % 5;
-                                    let times_completed = i / 5;
-                                    let i10 = i % 10;
-                                    let direction = if i10 >= 5 {
-                                        TweeningDirection::Backward
-                                    } else {
-                                        TweeningDirection::Forward
-                                    };
-                                    let just_completed = i5 == 0;
-                                    (
-                                        progress,
-                                        times_completed,
-                                        direction,
-                                        TweenState::Active,
-                                        just_completed,
-                                    )
-                                }
-                            }
-                            RepeatCount::For(_) => panic!("Untested"),
-                        };
-                    let factor = if tweening_direction.is_backward() {
-                        direction = !direction;
-                        1. - progress
-                    } else {
-                        progress
-                    };
-                    let expected_translation = if direction.is_forward() {
-                        Vec3::splat(progress)
-                    } else {
-                        Vec3::splat(1. - progress)
-                    };
-                    println!(
-                        "Expected: progress={} factor={} times_completed={} direction={:?} state={:?} just_completed={} translation={:?}",
-                        progress, factor, times_completed, direction, expected_state, just_completed, expected_translation
-                    );
+        manual_tick_component(Duration::from_millis(300), &mut marked, &mut world, entity);
+        assert_eq!(drain_markers(&mut world), vec![0, 1]);
+        assert!(drain_events(&mut world).is_empty());
+        assert_approx_eq!(position_x(&world, entity), 0.3);
 
-                    // Tick the tween
-                    let actual_state =
-                        manual_tick_component(tick_duration, &mut tween, &mut world, entity);
+        // Skip several markers at once, across an iteration. Markers are
+        // distinct from the completion of the wrapped tween.
+        let state =
+            manual_tick_component(Duration::from_millis(1500), &mut marked, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(drain_markers(&mut world), vec![2, 3, 4, 0, 1, 2, 3]);
+        assert_eq!(drain_events(&mut world), vec![9]);
+        assert_eq!(callback_monitor.lock().unwrap().invoke_count, 2);
 
-                    // Propagate events
-                    {
-                        let mut events = world.resource_mut::<Events<TweenCompleted>>();
-                        events.update();
-                    }
+        // Completing doesn't start a new iteration
+        let state =
+            manual_tick_component(Duration::from_millis(500), &mut marked, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(drain_markers(&mut world), vec![4]);
+        assert_eq!(drain_events(&mut world), vec![9]);
+        manual_tick_component(Duration::from_millis(500), &mut marked, &mut world, entity);
+        assert!(drain_markers(&mut world).is_empty());
+        assert!(drain_events(&mut world).is_empty());
+
+        // Ticking backward doesn't reach any marker
+        manual_tick_backward_component(
+            Duration::from_millis(1500),
+            &mut marked,
+            &mut world,
+            entity,
+        );
+        assert!(drain_markers(&mut world).is_empty());
+        assert_eq!(marked.elapsed(), Duration::from_millis(500));
 
-                    // Check actual values
-                    assert_eq!(tween.direction(), direction);
-                    assert_eq!(actual_state, expected_state);
-                    assert_approx_eq!(tween.progress(), progress);
-                    assert_eq!(tween.times_completed(), times_completed);
-                    let transform = world.entity(entity).get::<Transform>().unwrap();
-                    assert!(transform
-                        .translation
-                        .abs_diff_eq(expected_translation, 1e-5));
-                    assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
-                    let cb_mon = callback_monitor.lock().unwrap();
-                    assert_eq!(cb_mon.invoke_count, times_completed as u64);
-                    assert_eq!(cb_mon.last_reported_count, times_completed);
-                    {
-                        let mut event_reader = event_reader_system_state.get_mut(&mut world);
-                        let event = event_reader.iter().next();
-                        if just_completed {
-                            assert!(event.is_some());
-                            if let Some(event) = event {
-                                assert_eq!(event.entity, entity);
-                                assert_eq!(event.user_data, USER_DATA);
-                            }
-                        } else {
-                            assert!(event.is_none());
-                        }
-                    }
-                }
+        // Rewinding allows reaching the start marker again
+        marked.rewind();
+        manual_tick_component(Duration::from_millis(100), &mut marked, &mut world, entity);
+        assert_eq!(drain_markers(&mut world), vec![0]);
+    }
 
-                // Rewind
-                tween.rewind();
-                assert_eq!(tween.direction(), *tweening_direction); // does not change
-                assert_approx_eq!(tween.progress(), 0.);
-                assert_eq!(tween.times_completed(), 0);
+    #[test]
+    fn marked_in_sequence() {
+        let mut seq = Delay::new(Duration::from_millis(500)).then(
+            Marked::new(make_test_tween()).with_marker(Marker::at_progress(0.5).with_event(1)),
+        );
 
-                // Dummy tick to update target
-                let actual_state =
-                    manual_tick_component(Duration::ZERO, &mut tween, &mut world, entity);
-                assert_eq!(actual_state, TweenState::Active);
-                let expected_translation = if tweening_direction.is_backward() {
-                    Vec3::ONE
-                } else {
-                    Vec3::ZERO
-                };
-                let transform = world.entity(entity).get::<Transform>().unwrap();
-                assert!(transform
-                    .translation
-                    .abs_diff_eq(expected_translation, 1e-5));
-                assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+        let (mut world, entity) = make_test_env();
 
-                // Clear callback
-                tween.clear_completed();
-                assert!(tween.on_completed.is_none());
+        manual_tick_component(Duration::from_millis(900), &mut seq, &mut world, entity);
+        assert!(drain_markers(&mut world).is_empty());
+        manual_tick_component(Duration::from_millis(200), &mut seq, &mut world, entity);
+        assert_eq!(drain_markers(&mut world), vec![1]);
+        manual_tick_component(Duration::from_millis(1000), &mut seq, &mut world, entity);
+        assert!(drain_markers(&mut world).is_empty());
+    }
 
-                // Clear event sending
-                tween.clear_completed_event();
-                assert!(tween.event_data.is_none());
-            }
-        }
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum TestPayload {
+        Opened,
+        Closed(u32),
+    }
+
+    fn drain_payloads(world: &mut World) -> Vec<TestPayload> {
+        let mut events = world.resource_mut::<Events<TestPayload>>();
+        events.drain().collect()
     }
 
     #[test]
-    fn tween_dir() {
-        let mut tween = make_test_tween();
+    fn completed_payload() {
+        let mut seq = make_test_tween()
+            .with_repeat_count(2)
+            .with_completed_event(1)
+            .with_completed_payload(TestPayload::Opened)
+            .then(
+                Delay::new(Duration::from_secs(1)).with_completed_payload(TestPayload::Closed(3)),
+            );
 
-        // Default
-        assert_eq!(tween.direction(), TweeningDirection::Forward);
-        assert_approx_eq!(tween.progress(), 0.0);
+        let (mut world, entity) = make_test_env();
+        world.init_resource::<Events<TestPayload>>();
 
-        // no-op
-        tween.set_direction(TweeningDirection::Forward);
-        assert_eq!(tween.direction(), TweeningDirection::Forward);
-        assert_approx_eq!(tween.progress(), 0.0);
+        manual_tick_component(Duration::from_millis(500), &mut seq, &mut world, entity);
+        assert!(drain_payloads(&mut world).is_empty());
 
-        // Backward
-        tween.set_direction(TweeningDirection::Backward);
-        assert_eq!(tween.direction(), TweeningDirection::Backward);
-        // progress is independent of direction
-        assert_approx_eq!(tween.progress(), 0.0);
+        manual_tick_component(Duration::from_millis(1000), &mut seq, &mut world, entity);
+        assert_eq!(drain_payloads(&mut world), vec![TestPayload::Opened]);
+        // The u64 path is unchanged
+        assert_eq!(drain_events(&mut world), vec![1]);
 
-        // Progress-invariant
-        tween.set_direction(TweeningDirection::Forward);
-        tween.set_progress(0.3);
-        assert_approx_eq!(tween.progress(), 0.3);
-        tween.set_direction(TweeningDirection::Backward);
-        // progress is independent of direction
-        assert_approx_eq!(tween.progress(), 0.3);
+        manual_tick_component(Duration::from_millis(1500), &mut seq, &mut world, entity);
+        assert_eq!(
+            drain_payloads(&mut world),
+            vec![TestPayload::Opened, TestPayload::Closed(3)]
+        );
+        assert_eq!(drain_events(&mut world), vec![1]);
+
+        // Clearing the payload stops raising it
+        let mut tween = make_test_tween().with_completed_payload(TestPayload::Opened);
+        tween.clear_completed_payload();
+        manual_tick_component(Duration::from_secs(1), &mut tween, &mut world, entity);
+        assert!(drain_payloads(&mut world).is_empty());
+        tween.set_completed_payload(TestPayload::Closed(0));
+        tween.rewind();
+        manual_tick_component(Duration::from_secs(1), &mut tween, &mut world, entity);
+        assert_eq!(drain_payloads(&mut world), vec![TestPayload::Closed(0)]);
+    }
+
+    #[test]
+    fn marker_payload() {
+        let mut marked = Marked::new(make_test_tween())
+            .with_marker(Marker::at_progress(0.5).with_payload(TestPayload::Closed(5)));
 
         let (mut world, entity) = make_test_env();
+        world.init_resource::<Events<TestPayload>>();
 
-        // Progress always increases alongside the current direction
-        tween.set_direction(TweeningDirection::Backward);
-        assert_approx_eq!(tween.progress(), 0.3);
-        manual_tick_component(Duration::from_millis(100), &mut tween, &mut world, entity);
-        assert_approx_eq!(tween.progress(), 0.4);
-        let transform = world.entity(entity).get::<Transform>().unwrap();
-        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.6), 1e-5));
+        manual_tick_component(Duration::from_millis(400), &mut marked, &mut world, entity);
+        assert!(drain_payloads(&mut world).is_empty());
+        manual_tick_component(Duration::from_millis(400), &mut marked, &mut world, entity);
+        assert_eq!(drain_payloads(&mut world), vec![TestPayload::Closed(5)]);
+        assert!(drain_events(&mut world).is_empty());
     }
 
+    #[derive(Default, Resource)]
+    struct DeferredCount(u32);
+
     #[test]
-    fn tween_elapsed() {
-        let mut tween = make_test_tween();
+    fn completed_deferred() {
+        let mut seq = make_test_tween()
+            .with_repeat_count(2)
+            .with_completed_deferred(|entity, world| {
+                world.resource_mut::<DeferredCount>().0 += 1;
+                world
+                    .entity_mut(entity)
+                    .get_mut::<Transform>()
+                    .unwrap()
+                    .scale = Vec3::splat(3.);
+            })
+            .then(
+                Delay::new(Duration::from_secs(1)).with_completed_deferred(|entity, world| {
+                    world.resource_mut::<DeferredCount>().0 += 10;
+                    world.despawn(entity);
+                }),
+            );
 
-        let duration = tween.duration();
-        let elapsed = tween.elapsed();
+        let (mut world, entity) = make_test_env();
+        world.init_resource::<DeferredCount>();
 
-        assert_eq!(elapsed, Duration::ZERO);
-        assert_eq!(duration, Duration::from_secs(1));
+        manual_tick_component(Duration::from_millis(500), &mut seq, &mut world, entity);
+        assert_eq!(world.resource::<DeferredCount>().0, 0);
 
-        for ms in [0, 1, 500, 100, 300, 999, 847, 1000, 900] {
-            let elapsed = Duration::from_millis(ms);
-            tween.set_elapsed(elapsed);
-            assert_eq!(tween.elapsed(), elapsed);
+        manual_tick_component(Duration::from_millis(1000), &mut seq, &mut world, entity);
+        assert_eq!(world.resource::<DeferredCount>().0, 1);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(3.), 1e-5));
 
-            let progress = (elapsed.as_secs_f64() / duration.as_secs_f64()) as f32;
-            assert_approx_eq!(tween.progress(), progress);
+        manual_tick_component(Duration::from_millis(1000), &mut seq, &mut world, entity);
+        assert_eq!(world.resource::<DeferredCount>().0, 2);
 
-            let times_completed = u32::from(ms == 1000);
-            assert_eq!(tween.times_completed(), times_completed);
-        }
+        manual_tick_component(Duration::from_millis(1000), &mut seq, &mut world, entity);
+        assert_eq!(world.resource::<DeferredCount>().0, 12);
+        assert!(world.get_entity(entity).is_none());
+
+        // Clearing the callback stops invoking it
+        let mut tween = make_test_tween().with_completed_deferred(|_, world| {
+            world.resource_mut::<DeferredCount>().0 += 1;
+        });
+        tween.clear_completed_deferred();
+        let entity = world.spawn(Transform::default()).id();
+        manual_tick_component(Duration::from_secs(1), &mut tween, &mut world, entity);
+        assert_eq!(world.resource::<DeferredCount>().0, 12);
+        tween.set_completed_deferred(|_, world| {
+            world.resource_mut::<DeferredCount>().0 += 100;
+        });
+        tween.rewind();
+        manual_tick_component(Duration::from_secs(1), &mut tween, &mut world, entity);
+        assert_eq!(world.resource::<DeferredCount>().0, 112);
     }
 
-    /// Test ticking a sequence of tweens.
     #[test]
-    fn seq_tick() {
-        let tween1 = Tween::new(
-            EaseMethod::Linear,
-            Duration::from_secs(1),
-            TransformPositionLens {
-                start: Vec3::ZERO,
-                end: Vec3::ONE,
-            },
-        );
-        let tween2 = Tween::new(
-            EaseMethod::Linear,
-            Duration::from_secs(1),
-            TransformRotationLens {
-                start: Quat::IDENTITY,
-                end: Quat::from_rotation_x(90_f32.to_radians()),
-            },
+    fn marker_deferred_callback() {
+        let mut marked = Marked::new(make_test_tween()).with_marker(
+            Marker::at_progress(0.5).with_deferred_callback(|_, world| {
+                world.resource_mut::<DeferredCount>().0 += 1;
+            }),
         );
-        let mut seq = tween1.then(tween2);
 
         let (mut world, entity) = make_test_env();
+        world.init_resource::<DeferredCount>();
 
-        for i in 1..=16 {
-            let state =
-                manual_tick_component(Duration::from_millis(200), &mut seq, &mut world, entity);
-            let transform = world.entity(entity).get::<Transform>().unwrap();
-            if i < 5 {
-                assert_eq!(state, TweenState::Active);
-                let r = i as f32 * 0.2;
-                assert_eq!(*transform, Transform::from_translation(Vec3::splat(r)));
-            } else if i < 10 {
-                assert_eq!(state, TweenState::Active);
-                let alpha_deg = (18 * (i - 5)) as f32;
-                assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
-                assert!(transform
-                    .rotation
-                    .abs_diff_eq(Quat::from_rotation_x(alpha_deg.to_radians()), 1e-5));
-            } else {
-                assert_eq!(state, TweenState::Completed);
-                assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
-                assert!(transform
-                    .rotation
-                    .abs_diff_eq(Quat::from_rotation_x(90_f32.to_radians()), 1e-5));
-            }
-        }
+        manual_tick_component(Duration::from_millis(400), &mut marked, &mut world, entity);
+        assert_eq!(world.resource::<DeferredCount>().0, 0);
+        manual_tick_component(Duration::from_millis(400), &mut marked, &mut world, entity);
+        assert_eq!(world.resource::<DeferredCount>().0, 1);
     }
 
-    /// Test crossing tween boundaries in one tick.
     #[test]
-    fn seq_tick_boundaries() {
-        let mut seq = Sequence::new((0..3).map(|i| {
-            Tween::new(
-                EaseMethod::Linear,
-                Duration::from_secs(1),
-                TransformPositionLens {
-                    start: Vec3::splat(i as f32),
-                    end: Vec3::splat((i + 1) as f32),
-                },
-            )
-            .with_repeat_count(RepeatCount::Finite(1))
-        }));
+    fn timeline_empty() {
+        let mut timeline = Timeline::new();
+        assert_eq!(timeline.duration(), Duration::ZERO);
 
         let (mut world, entity) = make_test_env();
-
-        // Tick halfway through the first tween, then in one tick:
-        // - Finish the first tween
-        // - Start and finish the second tween
-        // - Start the third tween
-        for delta_ms in [500, 2000] {
-            manual_tick_component(
-                Duration::from_millis(delta_ms),
-                &mut seq,
-                &mut world,
-                entity,
-            );
-        }
-        assert_eq!(seq.index(), 2);
-        let transform = world.entity(entity).get::<Transform>().unwrap();
-        assert!(transform.translation.abs_diff_eq(Vec3::splat(2.5), 1e-5));
+        let state = manual_tick_component::<Transform>(
+            Duration::from_millis(100),
+            &mut timeline,
+            &mut world,
+            entity,
+        );
+        assert_eq!(state, TweenState::Completed);
     }
 
-    /// Sequence::new() and various Sequence-specific methods
-    #[test]
-    fn seq_iter() {
-        let mut seq = Sequence::new((1..5).map(|i| {
-            Tween::new(
-                EaseMethod::Linear,
-                Duration::from_millis(200 * i),
-                TransformPositionLens {
-                    start: Vec3::ZERO,
-                    end: Vec3::ONE,
-                },
-            )
-        }));
+    /// Minimal deterministic pseudo-random generator (xorshift64*) for property
+    /// tests.
+    struct TestRng(u64);
 
-        let mut progress = 0.;
-        for i in 1..5 {
-            assert_eq!(seq.index(), i - 1);
-            assert_approx_eq!(seq.progress(), progress);
-            let duration = Duration::from_millis(200 * i as u64);
-            assert_eq!(seq.current().duration(), duration);
-            progress += 0.25;
-            seq.set_progress(progress);
-            assert_eq!(seq.times_completed(), u32::from(i == 4));
+    impl TestRng {
+        fn new(seed: u64) -> Self {
+            Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
         }
 
-        seq.rewind();
-        assert_eq!(seq.progress(), 0.);
-        assert_eq!(seq.times_completed(), 0);
-    }
+        /// Get a random value in `0..n`.
+        fn below(&mut self, n: u64) -> u64 {
+            self.0 ^= self.0 >> 12;
+            self.0 ^= self.0 << 25;
+            self.0 ^= self.0 >> 27;
+            (self.0.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 32) % n
+        }
 
-    /// Sequence::from_single()
-    #[test]
-    fn seq_from_single() {
-        let tween = Tween::new(
-            EaseMethod::Linear,
-            Duration::from_secs(1),
-            TransformPositionLens {
-                start: Vec3::ZERO,
-                end: Vec3::ONE,
-            },
-        );
-        let seq = Sequence::from_single(tween);
+        fn millis(&mut self, min: u64, max: u64) -> Duration {
+            Duration::from_millis(min + self.below(max - min + 1))
+        }
 
-        assert_eq!(seq.duration(), Duration::from_secs(1));
+        fn direction(&mut self) -> TweeningDirection {
+            if self.below(3) == 0 {
+                TweeningDirection::Backward
+            } else {
+                TweeningDirection::Forward
+            }
+        }
+
+        fn repeat(&mut self) -> (u32, RepeatStrategy) {
+            let count = 1 + self.below(3) as u32;
+            let strategy = if self.below(2) == 0 {
+                RepeatStrategy::Repeat
+            } else {
+                RepeatStrategy::MirroredRepeat
+            };
+            (count, strategy)
+        }
     }
 
-    #[test]
-    fn seq_elapsed() {
-        let mut seq = Sequence::new((1..5).map(|i| {
-            Tween::new(
-                EaseMethod::Linear,
-                Duration::from_millis(200 * i),
-                TransformPositionLens {
-                    start: Vec3::ZERO,
-                    end: Vec3::ONE,
-                },
+    /// Build a random tree of tweenables for property tests. The same seed
+    /// always produces the same tree.
+    ///
+    /// Tweens only animate the given `fields` of the [`Transform`], each one of
+    /// 0 (translation), 1 (scale), or 2 (rotation around Z). The target state
+    /// of parallel tracks animating the same field depends on how the playback
+    /// time is split into ticks, so each track animates different fields.
+    fn make_random_tweenable(
+        rng: &mut TestRng,
+        depth: u32,
+        fields: &[u64],
+    ) -> BoxedTweenable<Transform> {
+        let kind = if depth >= 3 {
+            rng.below(2)
+        } else {
+            rng.below(4)
+        };
+        let (count, strategy) = rng.repeat();
+        let direction = rng.direction();
+        match kind {
+            0 => {
+                let duration = rng.millis(100, 1000);
+                let start = rng.below(10) as f32;
+                let end = rng.below(10) as f32;
+                let tween = match fields[rng.below(fields.len() as u64) as usize] {
+                    0 => Tween::new(
+                        EaseMethod::Linear,
+                        duration,
+                        TransformPositionLens {
+                            start: Vec3::splat(start),
+                            end: Vec3::splat(end),
+                        },
+                    ),
+                    1 => Tween::new(
+                        EaseMethod::Linear,
+                        duration,
+                        TransformScaleLens {
+                            start: Vec3::splat(start),
+                            end: Vec3::splat(end),
+                        },
+                    ),
+                    _ => Tween::new(
+                        EaseMethod::Linear,
+                        duration,
+                        TransformRotateZLens {
+                            start: start * 0.1,
+                            end: end * 0.1,
+                        },
+                    ),
+                };
+                tween
+                    .with_repeat_count(count)
+                    .with_repeat_strategy(strategy)
+                    .with_direction(direction)
+                    .into()
+            }
+            1 => Delay::new(rng.millis(100, 500)).into(),
+            2 => {
+                let len = 1 + rng.below(4);
+                Sequence::new((0..len).map(|_| make_random_tweenable(rng, depth + 1, fields)))
+                    .with_repeat_count(count)
+                    .with_repeat_strategy(strategy)
+                    .with_direction(direction)
+                    .into()
+            }
+            _ => Tracks::new(
+                fields
+                    .iter()
+                    .map(|field| make_random_tweenable(rng, depth + 1, &[*field]))
+                    .collect::<Vec<_>>(),
             )
-        }));
-
-        let mut elapsed = Duration::ZERO;
-        for i in 1..5 {
-            assert_eq!(seq.index(), i - 1);
-            assert_eq!(seq.elapsed(), elapsed);
-            let duration = Duration::from_millis(200 * i as u64);
-            assert_eq!(seq.current().duration(), duration);
-            elapsed += duration;
-            seq.set_elapsed(elapsed);
-            assert_eq!(seq.times_completed(), u32::from(i == 4));
+            .with_offsets(
+                fields
+                    .iter()
+                    .map(|_| rng.millis(0, 300))
+                    .collect::<Vec<_>>(),
+            )
+            .with_repeat_count(count)
+            .with_repeat_strategy(strategy)
+            .with_direction(direction)
+            .into(),
         }
     }
 
-    /// Test ticking parallel tracks of tweens.
-    #[test]
-    fn tracks_tick() {
-        let tween1 = Tween::new(
+    /// Build a random sequence for property tests.
+    ///
+    /// The sequence first plays a translation tween, so that the translation is
+    /// always animated from the start. When playing backward, that tween is the
+    /// last child instead.
+    fn make_random_seq(seed: u64, fields: &[u64]) -> Sequence<Transform> {
+        let mut rng = TestRng::new(seed);
+        let (count, strategy) = rng.repeat();
+        let direction = rng.direction();
+        let len = 1 + rng.below(4);
+        let first = Tween::new(
             EaseMethod::Linear,
-            Duration::from_millis(1000),
+            rng.millis(100, 1000),
             TransformPositionLens {
                 start: Vec3::ZERO,
                 end: Vec3::ONE,
             },
         );
-        let tween2 = Tween::new(
-            EaseMethod::Linear,
-            Duration::from_millis(800), // shorter
-            TransformRotationLens {
-                start: Quat::IDENTITY,
-                end: Quat::from_rotation_x(90_f32.to_radians()),
-            },
-        );
-        let mut tracks = Tracks::new([tween1, tween2]);
-        assert_eq!(tracks.duration(), Duration::from_secs(1)); // max(1., 0.8)
+        let mut tweens: Vec<_> = (0..len)
+            .map(|_| make_random_tweenable(&mut rng, 1, fields))
+            .collect();
+        if direction.is_backward() {
+            tweens.push(first.with_direction(direction).into());
+        } else {
+            tweens.insert(0, first.into());
+        }
+        Sequence::new(tweens)
+            .with_repeat_count(count)
+            .with_repeat_strategy(strategy)
+            .with_direction(direction)
+    }
 
+    /// Play a tweenable continuously with random ticks until it completes,
+    /// recording the elapsed time and target state after each tick.
+    fn record_playback(
+        tweenable: &mut dyn Tweenable<Transform>,
+        rng: &mut TestRng,
+    ) -> Vec<(Duration, Transform)> {
         let (mut world, entity) = make_test_env();
-
-        for i in 1..=6 {
-            let state =
-                manual_tick_component(Duration::from_millis(200), &mut tracks, &mut world, entity);
-            let transform = world.entity(entity).get::<Transform>().unwrap();
-            if i < 5 {
-                assert_eq!(state, TweenState::Active);
-                assert_eq!(tracks.times_completed(), 0);
-                let r = i as f32 * 0.2;
-                assert_approx_eq!(tracks.progress(), r);
-                let alpha_deg = 22.5 * i as f32;
-                assert!(transform.translation.abs_diff_eq(Vec3::splat(r), 1e-5));
-                assert!(transform
-                    .rotation
-                    .abs_diff_eq(Quat::from_rotation_x(alpha_deg.to_radians()), 1e-5));
+        let mut records = vec![];
+        loop {
+            let delta = if rng.below(8) == 0 {
+                Duration::ZERO
             } else {
-                assert_eq!(state, TweenState::Completed);
-                assert_eq!(tracks.times_completed(), 1);
-                assert_approx_eq!(tracks.progress(), 1.);
-                assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
-                assert!(transform
-                    .rotation
-                    .abs_diff_eq(Quat::from_rotation_x(90_f32.to_radians()), 1e-5));
+                rng.millis(1, 700)
+            };
+            let state = manual_tick_component(delta, tweenable, &mut world, entity);
+            let transform = *world.entity(entity).get::<Transform>().unwrap();
+            records.push((tweenable.elapsed(), transform));
+            if state == TweenState::Completed {
+                return records;
             }
         }
+    }
 
-        tracks.rewind();
-        assert_eq!(tracks.times_completed(), 0);
-        assert_approx_eq!(tracks.progress(), 0.);
-
-        tracks.set_progress(0.9);
-        assert_approx_eq!(tracks.progress(), 0.9);
-        // tick to udpate state (set_progress() does not update state)
-        let state = manual_tick_component(Duration::ZERO, &mut tracks, &mut world, entity);
-        assert_eq!(state, TweenState::Active);
-        assert_eq!(tracks.times_completed(), 0);
+    fn assert_same_transform(actual: &Transform, expected: &Transform, context: &str) {
+        assert!(
+            actual.translation.abs_diff_eq(expected.translation, 1e-4)
+                && actual.scale.abs_diff_eq(expected.scale, 1e-4)
+                && actual.rotation.abs_diff_eq(expected.rotation, 1e-4),
+            "{}: actual={:?} expected={:?}",
+            context,
+            actual,
+            expected
+        );
+    }
 
-        tracks.set_progress(3.2);
-        assert_approx_eq!(tracks.progress(), 1.);
-        // tick to udpate state (set_progress() does not update state)
-        let state = manual_tick_component(Duration::ZERO, &mut tracks, &mut world, entity);
-        assert_eq!(state, TweenState::Completed);
-        assert_eq!(tracks.times_completed(), 1); // no looping
+    /// Property test: seeking a fresh sequence to any time then ticking with a
+    /// zero delta produces the same target state as a continuous playback.
+    #[test]
+    fn seq_seek_matches_playback() {
+        for seed in 0..64 {
+            let mut rng = TestRng::new(seed);
+            let mut seq = make_random_seq(seed, &[0, 1, 2]);
+            let records = record_playback(&mut seq, &mut rng);
+
+            for (elapsed, expected) in &records {
+                let mut seq = make_random_seq(seed, &[0, 1, 2]);
+                let (mut world, entity) = make_test_env();
+                seq.set_elapsed(*elapsed);
+                assert_eq!(seq.elapsed(), *elapsed);
+                manual_tick_component(Duration::ZERO, &mut seq, &mut world, entity);
+                let actual = world.entity(entity).get::<Transform>().unwrap();
+                let context = format!("seed={} elapsed={:?}", seed, elapsed);
+                assert_same_transform(actual, expected, &context);
+            }
+        }
+    }
 
-        tracks.set_progress(-0.5);
-        assert_approx_eq!(tracks.progress(), 0.);
-        // tick to udpate state (set_progress() does not update state)
-        let state = manual_tick_component(Duration::ZERO, &mut tracks, &mut world, entity);
-        assert_eq!(state, TweenState::Active);
-        assert_eq!(tracks.times_completed(), 0); // no looping
+    /// Property test: seeking back and forth a sequence already playing produces
+    /// the same target state as a continuous playback, and then resumes ticking
+    /// like a continuous playback. Only the translation is animated, so that the
+    /// target state doesn't depend on the state before the seek.
+    #[test]
+    fn seq_seek_back_and_forth() {
+        for seed in 0..64 {
+            let mut rng = TestRng::new(seed);
+            let mut seq = make_random_seq(seed, &[0]);
+            let records = record_playback(&mut seq, &mut rng);
+
+            let (mut world, entity) = make_test_env();
+            let mut seq = make_random_seq(seed, &[0]);
+            for _ in 0..50 {
+                let index = rng.below(records.len() as u64) as usize;
+                let (elapsed, expected) = &records[index];
+                seq.set_elapsed(*elapsed);
+                manual_tick_component(Duration::ZERO, &mut seq, &mut world, entity);
+                let actual = world.entity(entity).get::<Transform>().unwrap();
+                let context = format!("seed={} seek={:?}", seed, elapsed);
+                assert_same_transform(actual, expected, &context);
+
+                // Resume playback up to the next record
+                if let Some((next_elapsed, expected)) = records.get(index + 1) {
+                    manual_tick_component(*next_elapsed - *elapsed, &mut seq, &mut world, entity);
+                    let actual = world.entity(entity).get::<Transform>().unwrap();
+                    let context = format!("seed={} seek={:?} resume", seed, elapsed);
+                    assert_same_transform(actual, expected, &context);
+                }
+            }
+        }
     }
 
     /// Delay::then()